@@ -0,0 +1,13 @@
+#![no_main]
+use aoc::year_2020::day_02::policy;
+use libfuzzer_sys::fuzz_target;
+
+// The first line is a policy, the rest are passwords to check with it.
+fuzz_target!(|data: &str| {
+    let (spec, passwords) = data.split_once('\n').unwrap_or((data, ""));
+    if let Ok(policy) = policy(spec) {
+        for password in passwords.lines() {
+            let _ = policy.violations(password);
+        }
+    }
+});
@@ -0,0 +1,7 @@
+#![no_main]
+use aoc::year_2020::day_12::Command;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = data.parse::<Command>();
+});
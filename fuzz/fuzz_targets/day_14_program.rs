@@ -0,0 +1,9 @@
+#![no_main]
+use aoc::puzzle::Solver;
+use aoc::year_2020::day_14::Puzzle;
+use libfuzzer_sys::fuzz_target;
+
+// The tasks are built line by line, so the whole program is parsed.
+fuzz_target!(|data: &str| {
+    let _ = Puzzle::parse(data);
+});
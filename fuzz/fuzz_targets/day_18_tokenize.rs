@@ -0,0 +1,12 @@
+#![no_main]
+use aoc::year_2020::day_18::{evaluate_tokens, parse_expression, tokenize, Precedence};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if tokenize(data).is_ok() {
+        // A well formed expression has balanced parentheses, so it can be grouped.
+        let expr = parse_expression(data).expect("a tokenized expression can't be grouped");
+        let _ = evaluate_tokens(&expr, Precedence::Equal);
+        let _ = evaluate_tokens(&expr, Precedence::AdditionFirst);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+use aoc::year_2020::day_19::{parse_rule, RuleItem};
+use libfuzzer_sys::fuzz_target;
+
+// Empty data would make the message matching loop forever.
+fn has_empty_data(rule: &[RuleItem]) -> bool {
+    rule.iter().any(|item| match item {
+        RuleItem::Data(data) => data.iter().any(String::is_empty),
+        RuleItem::Pair(a, b) => has_empty_data(a) || has_empty_data(b),
+        RuleItem::Link(_) => false,
+    })
+}
+
+fuzz_target!(|data: &str| {
+    if let Ok(rule) = parse_rule(data, data) {
+        assert!(!rule.is_empty());
+        assert!(!has_empty_data(&rule));
+    }
+});
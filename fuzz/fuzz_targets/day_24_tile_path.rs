@@ -0,0 +1,31 @@
+#![no_main]
+use aoc::year_2020::day_24::{HexDir, HexPos};
+use libfuzzer_sys::fuzz_target;
+
+// The shortest path to a tile: first north-east or south-west, then east or west.
+fn print_path(pos: &HexPos) -> String {
+    let (x, y) = pos.coordinates();
+    let diagonal = if y >= 0 {
+        HexDir::NorthEast
+    } else {
+        HexDir::SouthWest
+    };
+    let straight = if x >= y { HexDir::East } else { HexDir::West };
+    let steps = (x - y).unsigned_abs() / 2;
+    let mut path = diagonal.name().repeat(y.unsigned_abs() as usize);
+    path.push_str(&straight.name().repeat(steps as usize));
+    path
+}
+
+fuzz_target!(|data: &str| {
+    if let Ok(pos) = data.parse::<HexPos>() {
+        let path = print_path(&pos);
+        assert_eq!(
+            path.parse::<HexPos>().ok(),
+            Some(pos),
+            "{:?} -> {:?}",
+            data,
+            path
+        );
+    }
+});
@@ -0,0 +1,113 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::{Answer, Answers};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+// The accepted answers keyed by day. The manifest is a small subset of TOML:
+//
+// [21]
+// part_1 = 2211
+// part_2 = "vv,nlxsmb,rnbhjk,bvnkk,ttxvphb,qmkz,trmzkcfg,jpvz"
+#[derive(Debug, Default)]
+pub struct Manifest {
+    days: HashMap<u8, Answers>,
+}
+
+fn parse_value(s: &str) -> Option<Answer> {
+    if let Some(text) = s.strip_prefix('"') {
+        Some(Answer::Text(text.strip_suffix('"')?.to_string()))
+    } else {
+        s.parse().ok().map(Answer::Number)
+    }
+}
+
+// The manifest form of an answer, the opposite of parse_value.
+pub fn format_value(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => format!("\"{}\"", s),
+    }
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut days: HashMap<u8, Answers> = HashMap::new();
+        for section in parse::sections(text)? {
+            let day = section
+                .name
+                .parse()
+                .map_err(|_| section.error("invalid day"))?;
+            let answers = days.entry(day).or_default();
+            for entry in &section.entries {
+                let value = parse_value(entry.value)
+                    .ok_or_else(|| entry.error(entry.value, "invalid value"))?;
+                match entry.key {
+                    "part_1" => answers.part_1 = Some(value),
+                    "part_2" => answers.part_2 = Some(value),
+                    key => return Err(entry.error(key, format!("unknown key '{}'", key))),
+                }
+            }
+        }
+
+        Ok(Self { days })
+    }
+
+    pub fn read(path: &str) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+        Self::parse(&text).map_err(|err| format!("{}: {}", path, err).into())
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Answers> {
+        self.days.get(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = Manifest::parse(
+            r#"
+# comment
+[1]
+part_1 = 514579
+part_2 = 241861950
+
+[21]
+part_2 = "mxmxvkd,sqjhc,fvjkl"
+"#,
+        )
+        .unwrap();
+
+        let day_1 = manifest.get(1).unwrap();
+        assert_eq!(day_1.part_1, Some(Answer::Number(514579)));
+        assert_eq!(day_1.part_2, Some(Answer::Number(241861950)));
+
+        let day_21 = manifest.get(21).unwrap();
+        assert_eq!(day_21.part_1, None);
+        assert_eq!(
+            day_21.part_2,
+            Some(Answer::Text(String::from("mxmxvkd,sqjhc,fvjkl")))
+        );
+        assert!(manifest.get(2).is_none());
+
+        let error = |s: &str| Manifest::parse(s).unwrap_err().to_string();
+        assert_eq!(
+            error("part_1 = 1"),
+            "line 1, column 1: value outside of a section"
+        );
+        assert_eq!(
+            error("[1]\npart_3 = 1"),
+            "line 2, column 1: unknown key 'part_3'"
+        );
+        assert_eq!(
+            error("[1]\npart_1 = one"),
+            "line 2, column 10: invalid value"
+        );
+        assert_eq!(error("[x]"), "line 1, column 2: invalid day");
+    }
+}
@@ -0,0 +1,638 @@
+use aoc::input::Source;
+use aoc::puzzle::Part;
+use aoc::report::Format;
+use aoc::scaffold;
+use aoc::year_2020::{day_02, generate};
+use std::fmt;
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+pub const DEFAULT_RUNS: usize = 10;
+
+pub const USAGE: &str = "\
+Usage: aoc [run] [DAYS] [--year YEAR] [--part 1|2] [--input FILE] [--time]
+                [--format text|json|csv] [--jobs N]
+       aoc verify [DAYS] [--year YEAR] [--part 1|2] [--time]
+       aoc watch DAY [--year YEAR] [--part 1|2]
+       aoc bench [DAYS] [--year YEAR] [--part 1|2] [--runs N]
+       aoc fetch [DAYS] [--year YEAR] [--force]
+       aoc generate DAY [--size N] [--seed N]
+       aoc new DAY [--year YEAR] [--title TITLE]
+       aoc audit --policy POLICY... [--input FILE]
+
+DAYS is 'all' (the default), a day number, a range like '1-10'
+or a comma separated list of those, e.g. '1,3,7-9'.
+YEAR is the year of the puzzles, the latest one by default.
+FILE replaces the puzzle input of a single day; '-' reads the standard input.
+'verify' checks the answers against the manifest in 'data/YEAR/answers.toml'.
+--time prints how long parsing and each part took.
+'watch' verifies the day with timing through 'cargo run' whenever its input or source
+file changes, so the solver is rebuilt each time; it runs until it is interrupted.
+--format json|csv prints one {day, part, answer, elapsed} record per answer,
+the elapsed time is in seconds.
+--jobs solves up to N days at once; the output keeps the order of the days.
+'bench' solves every day N times (10 by default) and reports min/median/max.
+'fetch' downloads the missing puzzle inputs into 'data/YEAR/' (--force replaces them).
+It needs the session cookie in AOC_SESSION; AOC_BASE_URL replaces the server.
+'generate' prints a random input of the 2020 days 7, 10, 19 or 21 to the standard
+output and the answers known by its construction to the standard error. --size
+scales the input, 1 (the default) is about an official input; --seed picks another one.
+'new' adds a solver skeleton of the day to 'src/', with its registry entry, an input
+placeholder and a calendar link in the README; a new YEAR gets its module as well.
+'audit' checks a password dump, one password on each line, against every --policy
+and reports the rules each password breaks. FILE is the standard input by default.
+POLICY is one of 'count:1-3 a', 'positions:1-3 a', 'min-length:12',
+'classes:lower,upper,digit,symbol', 'forbid:password,qwerty' or 'regex:^[a-z]+$'.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    Run {
+        year: u16,
+        days: Vec<u8>,
+        part: Part,
+        input: Source,
+        time: bool,
+        format: Format,
+        jobs: usize,
+    },
+    Verify {
+        year: u16,
+        days: Vec<u8>,
+        part: Part,
+        time: bool,
+    },
+    Watch {
+        year: u16,
+        day: u8,
+        part: Part,
+    },
+    Bench {
+        year: u16,
+        days: Vec<u8>,
+        part: Part,
+        runs: usize,
+    },
+    Fetch {
+        year: u16,
+        days: Vec<u8>,
+        force: bool,
+    },
+    Generate {
+        day: u8,
+        size: usize,
+        seed: u64,
+    },
+    New {
+        year: u16,
+        day: u8,
+        title: String,
+    },
+    Audit {
+        policies: Vec<String>,
+        input: Source,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct CliError {
+    pub message: String,
+}
+
+impl CliError {
+    fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.message)
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, CliError> {
+    match s.trim().parse() {
+        Ok(day) if (FIRST_DAY..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(CliError::new(format!(
+            "Invalid day: '{}' (expected {}-{})",
+            s, FIRST_DAY, LAST_DAY
+        ))),
+    }
+}
+
+// all | <day> | <first>-<last> | a comma separated list of those.
+pub fn parse_days(s: &str) -> Result<Vec<u8>, CliError> {
+    let mut days = Vec::new();
+    for item in s.split(',') {
+        if item.trim() == "all" {
+            days.extend(FIRST_DAY..=LAST_DAY);
+        } else if let Some((first, last)) = item.split_once('-') {
+            let first = parse_day(first)?;
+            let last = parse_day(last)?;
+            if first > last {
+                return Err(CliError::new(format!("Invalid day range: '{}'", item)));
+            }
+            days.extend(first..=last);
+        } else {
+            days.push(parse_day(item)?);
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_year(s: &str) -> Result<u16, CliError> {
+    match s.parse() {
+        Ok(year) if aoc::year(year).is_some() => Ok(year),
+        _ => {
+            let years: Vec<String> = aoc::YEARS.iter().map(|y| y.year.to_string()).collect();
+            Err(CliError::new(format!(
+                "Invalid year: '{}' (expected {})",
+                s,
+                years.join(", ")
+            )))
+        }
+    }
+}
+
+// A new day can start a new year.
+fn parse_new_year(s: &str) -> Result<u16, CliError> {
+    match s.parse() {
+        Ok(year) if year >= scaffold::FIRST_YEAR => Ok(year),
+        _ => Err(CliError::new(format!(
+            "Invalid year: '{}' (expected {} or later)",
+            s,
+            scaffold::FIRST_YEAR
+        ))),
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, CliError> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(CliError::new(format!(
+            "Invalid part: '{}' (expected 1 or 2)",
+            s
+        ))),
+    }
+}
+
+fn parse_count(s: &str) -> Result<usize, CliError> {
+    match s.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(CliError::new(format!(
+            "Invalid count: '{}' (expected a positive number)",
+            s
+        ))),
+    }
+}
+
+fn parse_seed(s: &str) -> Result<u64, CliError> {
+    s.parse()
+        .map_err(|_| CliError::new(format!("Invalid seed: '{}'", s)))
+}
+
+// The policy is parsed here to report a bad one early, and again by the audit.
+fn parse_policy(s: &str) -> Result<String, CliError> {
+    match day_02::policy(s) {
+        Ok(_) => Ok(s.to_string()),
+        Err(err) => Err(CliError::new(format!("Invalid policy: '{}' ({})", s, err))),
+    }
+}
+
+fn option_value<I>(args: &mut I, option: &str) -> Result<String, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    args.next()
+        .ok_or_else(|| CliError::new(format!("Missing value for {}", option)))
+}
+
+fn unsupported(option: &str, command: &str) -> CliError {
+    CliError::new(format!(
+        "Option {} can't be used with '{}'",
+        option, command
+    ))
+}
+
+pub fn parse_args<I>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+
+    // The 'run' command is the default one.
+    let command = match args.peek().map(String::as_str) {
+        Some(
+            name @ ("run" | "verify" | "bench" | "fetch" | "generate" | "new" | "watch" | "audit"),
+        ) => {
+            let name = name.to_string();
+            args.next();
+            name
+        }
+        _ => String::from("run"),
+    };
+
+    let mut days: Option<Vec<u8>> = None;
+    let mut year = None;
+    let mut part = None;
+    let mut input = None;
+    let mut time = false;
+    let mut runs = None;
+    let mut format = None;
+    let mut jobs = None;
+    let mut force = false;
+    let mut size = None;
+    let mut seed = None;
+    let mut title = None;
+    let mut policies = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" | "help" => return Ok(Command::Help),
+            "-y" | "--year" => {
+                let value = option_value(&mut args, &arg)?;
+                year = Some(match command.as_str() {
+                    "new" => parse_new_year(&value)?,
+                    _ => parse_year(&value)?,
+                });
+            }
+            "-p" | "--part" => part = Some(parse_part(&option_value(&mut args, &arg)?)?),
+            "-i" | "--input" => input = Some(Source::from_arg(&option_value(&mut args, &arg)?)),
+            "-t" | "--time" => time = true,
+            "-f" | "--format" => {
+                let value = option_value(&mut args, &arg)?;
+                let value = Format::from_arg(&value)
+                    .ok_or_else(|| CliError::new(format!("Invalid format: '{}'", value)))?;
+                format = Some(value);
+            }
+            "-r" | "--runs" => runs = Some(parse_count(&option_value(&mut args, &arg)?)?),
+            "-j" | "--jobs" => jobs = Some(parse_count(&option_value(&mut args, &arg)?)?),
+            "--force" => force = true,
+            "--size" => size = Some(parse_count(&option_value(&mut args, &arg)?)?),
+            "--title" => title = Some(option_value(&mut args, &arg)?),
+            "--seed" => seed = Some(parse_seed(&option_value(&mut args, &arg)?)?),
+            "--policy" => policies.push(parse_policy(&option_value(&mut args, &arg)?)?),
+            s if s.starts_with('-') && s != "-" => {
+                return Err(CliError::new(format!("Unknown option: '{}'", s)));
+            }
+            s if days.is_none() => days = Some(parse_days(s)?),
+            s => return Err(CliError::new(format!("Unexpected argument: '{}'", s))),
+        }
+    }
+
+    if command == "generate" {
+        // A generated input is for a single day, so the day is required.
+        let day = match days.as_deref() {
+            Some(&[day]) if generate::DAYS.contains(&day) => day,
+            _ => {
+                return Err(CliError::new(format!(
+                    "'generate' needs one of the days {:?}",
+                    generate::DAYS
+                )))
+            }
+        };
+        // The generators are written for the 2020 puzzles.
+        let other = year.is_some() || part.is_some() || input.is_some() || time || format.is_some();
+        let other = other || !policies.is_empty();
+        if other || runs.is_some() || jobs.is_some() || force || title.is_some() {
+            return Err(CliError::new(String::from(
+                "'generate' takes the --size and --seed options only",
+            )));
+        }
+        return Ok(Command::Generate {
+            day,
+            size: size.unwrap_or(generate::DEFAULT_SIZE),
+            seed: seed.unwrap_or(generate::DEFAULT_SEED),
+        });
+    }
+    if command == "new" {
+        let day = match days.as_deref() {
+            Some(&[day]) => day,
+            _ => return Err(CliError::new(String::from("'new' needs a single day"))),
+        };
+        let other = part.is_some() || input.is_some() || time || format.is_some();
+        let other = other || !policies.is_empty();
+        if other || runs.is_some() || jobs.is_some() || force || size.is_some() || seed.is_some() {
+            return Err(CliError::new(String::from(
+                "'new' takes the --year and --title options only",
+            )));
+        }
+        return Ok(Command::New {
+            year: year.unwrap_or_else(aoc::latest_year),
+            day,
+            title: title.unwrap_or_else(|| String::from(scaffold::DEFAULT_TITLE)),
+        });
+    }
+    if command == "audit" {
+        // The dump is not a puzzle input of a day.
+        let other = days.is_some() || year.is_some() || part.is_some() || time || format.is_some();
+        let scaffolding = title.is_some() || size.is_some() || seed.is_some();
+        if other || scaffolding || runs.is_some() || jobs.is_some() || force {
+            return Err(CliError::new(String::from(
+                "'audit' takes the --policy and --input options only",
+            )));
+        }
+        if policies.is_empty() {
+            return Err(CliError::new(String::from("'audit' needs a --policy")));
+        }
+        return Ok(Command::Audit {
+            policies,
+            input: input.unwrap_or(Source::Stdin),
+        });
+    }
+    if !policies.is_empty() {
+        return Err(unsupported("--policy", &command));
+    }
+    if title.is_some() {
+        return Err(unsupported("--title", &command));
+    }
+    if size.is_some() {
+        return Err(unsupported("--size", &command));
+    }
+    if seed.is_some() {
+        return Err(unsupported("--seed", &command));
+    }
+
+    let days = days.unwrap_or_else(|| (FIRST_DAY..=LAST_DAY).collect());
+    if command != "run" {
+        if input.is_some() {
+            return Err(unsupported("--input", &command));
+        }
+        if time && command != "verify" {
+            return Err(unsupported("--time", &command));
+        }
+        if format.is_some() {
+            return Err(unsupported("--format", &command));
+        }
+        if jobs.is_some() {
+            return Err(unsupported("--jobs", &command));
+        }
+    }
+    if command != "bench" && runs.is_some() {
+        return Err(unsupported("--runs", &command));
+    }
+    if command == "fetch" && part.is_some() {
+        return Err(unsupported("--part", &command));
+    }
+    if command != "fetch" && force {
+        return Err(unsupported("--force", &command));
+    }
+
+    let year = year.unwrap_or_else(aoc::latest_year);
+    let part = part.unwrap_or(Part::Both);
+    match command.as_str() {
+        "fetch" => Ok(Command::Fetch { year, days, force }),
+        "verify" => Ok(Command::Verify {
+            year,
+            days,
+            part,
+            time,
+        }),
+        "watch" => match days[..] {
+            [day] => Ok(Command::Watch { year, day, part }),
+            _ => Err(CliError::new(String::from("'watch' needs a single day"))),
+        },
+        "bench" => Ok(Command::Bench {
+            year,
+            days,
+            part,
+            runs: runs.unwrap_or(DEFAULT_RUNS),
+        }),
+        _ => {
+            let input = input.unwrap_or(Source::Default);
+            let format = format.unwrap_or(Format::Text);
+            if time && format != Format::Text {
+                return Err(CliError::new(String::from(
+                    "The timing table can be printed in the text format only",
+                )));
+            }
+            if input != Source::Default && days.len() != 1 {
+                return Err(CliError::new(String::from(
+                    "An input file can be given for a single day only",
+                )));
+            }
+            Ok(Command::Run {
+                year,
+                days,
+                part,
+                input,
+                time,
+                format,
+                jobs: jobs.unwrap_or(1),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn day_selection() {
+        assert_eq!(parse_days("7").unwrap(), vec![7]);
+        assert_eq!(parse_days("1-3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse_days("9,1-3,2").unwrap(), vec![1, 2, 3, 9]);
+        assert_eq!(parse_days("all").unwrap().len(), 25);
+
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            parse_args(args("")).unwrap(),
+            Command::Run {
+                year: aoc::latest_year(),
+                days: (1..=25).collect(),
+                part: Part::Both,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                jobs: 1
+            }
+        );
+        assert_eq!(
+            parse_args(args("run 7 --part 2")).unwrap(),
+            Command::Run {
+                year: aoc::latest_year(),
+                days: vec![7],
+                part: Part::Two,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                jobs: 1
+            }
+        );
+        assert_eq!(
+            parse_args(args("1-10 -p 1 --jobs 4")).unwrap(),
+            Command::Run {
+                year: aoc::latest_year(),
+                days: (1..=10).collect(),
+                part: Part::One,
+                input: Source::Default,
+                time: false,
+                format: Format::Text,
+                jobs: 4
+            }
+        );
+        assert_eq!(
+            parse_args(args("8 --input my-input.txt --format json")).unwrap(),
+            Command::Run {
+                year: aoc::latest_year(),
+                days: vec![8],
+                part: Part::Both,
+                input: Source::File("my-input.txt".into()),
+                time: false,
+                format: Format::Json,
+                jobs: 1
+            }
+        );
+        assert_eq!(
+            parse_args(args("run 8 -i - --time")).unwrap(),
+            Command::Run {
+                year: aoc::latest_year(),
+                days: vec![8],
+                part: Part::Both,
+                input: Source::Stdin,
+                time: true,
+                format: Format::Text,
+                jobs: 1
+            }
+        );
+        assert_eq!(
+            parse_args(args("verify 1-3 --year 2020")).unwrap(),
+            Command::Verify {
+                year: 2020,
+                days: vec![1, 2, 3],
+                part: Part::Both,
+                time: false
+            }
+        );
+        assert_eq!(
+            parse_args(args("watch 8 --part 2")).unwrap(),
+            Command::Watch {
+                year: aoc::latest_year(),
+                day: 8,
+                part: Part::Two
+            }
+        );
+        assert_eq!(
+            parse_args(args("bench 15 --runs 3")).unwrap(),
+            Command::Bench {
+                year: aoc::latest_year(),
+                days: vec![15],
+                part: Part::Both,
+                runs: 3
+            }
+        );
+        assert_eq!(
+            parse_args(args("fetch 13,15 --force")).unwrap(),
+            Command::Fetch {
+                year: aoc::latest_year(),
+                days: vec![13, 15],
+                force: true
+            }
+        );
+        assert_eq!(
+            parse_args(args("generate 19 --size 100 --seed 7")).unwrap(),
+            Command::Generate {
+                day: 19,
+                size: 100,
+                seed: 7
+            }
+        );
+        assert_eq!(
+            parse_args(args("generate 7")).unwrap(),
+            Command::Generate {
+                day: 7,
+                size: 1,
+                seed: 2020
+            }
+        );
+        assert_eq!(
+            parse_args(vec![
+                String::from("new"),
+                String::from("1"),
+                String::from("--year"),
+                String::from("2021"),
+                String::from("--title"),
+                String::from("Sonar Sweep"),
+            ])
+            .unwrap(),
+            Command::New {
+                year: 2021,
+                day: 1,
+                title: String::from("Sonar Sweep")
+            }
+        );
+        assert_eq!(
+            parse_args(args("audit --policy min-length:12 --policy classes:digit")).unwrap(),
+            Command::Audit {
+                policies: vec![String::from("min-length:12"), String::from("classes:digit")],
+                input: Source::Stdin
+            }
+        );
+        assert_eq!(
+            parse_args(args("audit -i dump.txt --policy forbid:password")).unwrap(),
+            Command::Audit {
+                policies: vec![String::from("forbid:password")],
+                input: Source::File("dump.txt".into())
+            }
+        );
+        assert_eq!(parse_args(args("--help")).unwrap(), Command::Help);
+
+        assert!(parse_args(args("run 7 --part 3")).is_err());
+        assert!(parse_args(args("run 7 --part")).is_err());
+        assert!(parse_args(args("run 7 8")).is_err());
+        assert!(parse_args(args("--fast")).is_err());
+        assert!(parse_args(args("1-2 --input my-input.txt")).is_err());
+        assert!(parse_args(args("verify 8 --input my-input.txt")).is_err());
+        assert!(parse_args(args("run 8 --runs 3")).is_err());
+        assert!(parse_args(args("bench 8 --runs 0")).is_err());
+        assert!(parse_args(args("bench 8 --time")).is_err());
+        assert!(parse_args(args("verify 8 --time")).is_ok());
+        assert!(parse_args(args("8 --format xml")).is_err());
+        assert!(parse_args(args("8 --format csv --time")).is_err());
+        assert!(parse_args(args("verify 8 --format csv")).is_err());
+        assert!(parse_args(args("run --jobs 0")).is_err());
+        assert!(parse_args(args("bench --jobs 2")).is_err());
+        assert!(parse_args(args("fetch 1 --part 1")).is_err());
+        assert!(parse_args(args("run 1 --force")).is_err());
+        assert!(parse_args(args("run --year 2019")).is_err());
+        assert!(parse_args(args("generate 7 --year 2020")).is_err());
+        assert!(parse_args(args("watch")).is_err());
+        assert!(parse_args(args("watch 1,2")).is_err());
+        assert!(parse_args(args("watch 1 --time")).is_err());
+        assert!(parse_args(args("new")).is_err());
+        assert!(parse_args(args("new 1-2")).is_err());
+        assert!(parse_args(args("new 1 --year 2014")).is_err());
+        assert!(parse_args(args("new 1 --part 1")).is_err());
+        assert!(parse_args(args("run 1 --title x")).is_err());
+        assert!(parse_args(args("generate")).is_err());
+        assert!(parse_args(args("generate 1")).is_err());
+        assert!(parse_args(args("generate 7,10")).is_err());
+        assert!(parse_args(args("generate 7 --size 0")).is_err());
+        assert!(parse_args(args("generate 7 --seed x")).is_err());
+        assert!(parse_args(args("generate 7 --part 1")).is_err());
+        assert!(parse_args(args("run 7 --seed 1")).is_err());
+        assert!(parse_args(args("audit")).is_err());
+        assert!(parse_args(args("audit --policy length:12")).is_err());
+        assert!(parse_args(args("audit 2 --policy min-length:12")).is_err());
+        assert!(parse_args(args("audit --policy min-length:12 --time")).is_err());
+        assert!(parse_args(args("run 2 --policy min-length:12")).is_err());
+        assert!(parse_args(args("generate 7 --policy min-length:12")).is_err());
+    }
+}
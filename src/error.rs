@@ -0,0 +1,78 @@
+use std::fmt;
+
+// A malformed puzzle input. Lines and columns are counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    // An error about the input as a whole.
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    // An error about a part of a line; `part` has to be a slice of `line`.
+    pub fn at<S: Into<String>>(line: &str, part: &str, message: S) -> Self {
+        Self {
+            line: None,
+            column: Some(column_of(line, part)),
+            message: message.into(),
+        }
+    }
+
+    // Line parsers don't know where they are, so the line is added by the caller.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            (None, Some(column)) => write!(f, "column {}: {}", column, self.message),
+            (None, None) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+// The column of the first character of `part` within `line`.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).wrapping_sub(start);
+    if offset <= line.len() && offset + part.len() <= line.len() && line.is_char_boundary(offset) {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locations() {
+        let line = "nop +0 x";
+        let err = ParseError::at(line, &line[7..], "unexpected 'x'").on_line(3);
+        assert_eq!((err.line, err.column), (Some(3), Some(8)));
+        assert_eq!(err.to_string(), "line 3, column 8: unexpected 'x'");
+
+        // The first line wins.
+        assert_eq!(err.on_line(5).line, Some(3));
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+    }
+}
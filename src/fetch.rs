@@ -0,0 +1,196 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// The session cookie of a logged in user, every user has their own inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+// Points the fetcher to another server, e.g. a mock one like "http://127.0.0.1:8000".
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "aoc input fetcher";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// Downloads puzzle inputs from '{base_url}/{year}/day/{day}/input'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| format!("{} is not set", SESSION_VAR))?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn download(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let url = self.url(year, day);
+        let body = if let Some(rest) = url.strip_prefix("http://") {
+            http_get(rest, &self.session)
+        } else if url.starts_with("https://") {
+            // There is no TLS in the standard library, so curl does the job.
+            curl_get(&url, &self.session)
+        } else {
+            Err(format!("unsupported URL: {}", url).into())
+        };
+        body.map_err(|err| format!("{}: {}", url, err).into())
+    }
+
+    // Downloads the input into the cache unless it is there already.
+    // An empty file is a placeholder, not an input. Returns true if the input has been downloaded.
+    pub fn fetch(
+        &self,
+        year: u16,
+        day: u8,
+        path: &Path,
+        force: bool,
+    ) -> Result<bool, Box<dyn Error>> {
+        let cached = fs::metadata(path).is_ok_and(|meta| meta.len() > 0);
+        if cached && !force {
+            return Ok(false);
+        }
+
+        let input = self.download(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, input).map_err(|err| format!("{}: {}", path.display(), err))?;
+        Ok(true)
+    }
+}
+
+// A plain HTTP/1.0 request, so the response is neither chunked nor kept alive.
+fn http_get(address: &str, session: &str) -> Result<String, Box<dyn Error>> {
+    let (host, path) = match address.find('/') {
+        Some(pos) => (&address[..pos], &address[pos..]),
+        None => (address, "/"),
+    };
+    let host_port = if host.contains(':') {
+        host.to_string()
+    } else {
+        format!("{}:80", host)
+    };
+
+    let mut stream = TcpStream::connect(host_port)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    write!(
+        stream,
+        "GET {} HTTP/1.0\r\nHost: {}\r\nCookie: session={}\r\nUser-Agent: {}\r\n\r\n",
+        path, host, session, USER_AGENT
+    )?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    let response = String::from_utf8(response)?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("malformed HTTP response")?;
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .ok_or("malformed HTTP status line")?;
+    if status != "200" {
+        // A short message tells what went wrong, a whole page doesn't.
+        let body = body.trim();
+        return Err(match body.lines().count() {
+            1 => format!("HTTP status {}: {}", status, body),
+            _ => format!("HTTP status {}", status),
+        }
+        .into());
+    }
+    Ok(body.to_string())
+}
+
+fn curl_get(url: &str, session: &str) -> Result<String, Box<dyn Error>> {
+    // The cookie goes through the standard input to keep it out of the process list.
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--header", "@-"])
+        .args(["--user-agent", USER_AGENT, url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("can't run curl: {}", err))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={}", session)?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves a single request and returns the request head.
+    fn mock_server(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            head
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn download_from_mock_server() {
+        let (url, server) =
+            mock_server("HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\n1721\n979\n");
+        let fetcher = Fetcher::new(&url, "secret\n");
+        assert_eq!(fetcher.download(2020, 1).unwrap(), "1721\n979\n");
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("GET /2020/day/1/input HTTP/1.0\r\n"));
+        assert!(head.contains("Cookie: session=secret\r\n"));
+
+        let (url, server) = mock_server("HTTP/1.0 400 Bad Request\r\n\r\nPlease log in.\n");
+        let err = Fetcher::new(&url, "expired")
+            .download(2020, 25)
+            .unwrap_err();
+        assert!(err.to_string().ends_with("HTTP status 400: Please log in."));
+        server.join().unwrap();
+    }
+}
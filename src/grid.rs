@@ -0,0 +1,405 @@
+use crate::error::ParseError;
+use crate::parse::{self, Line};
+use std::fmt;
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+// A cell of a grid as (x, y). The origin is the top left corner, y grows downwards.
+pub type Pos = (usize, usize);
+
+// A step between two cells as (dx, dy).
+pub type Dir = (isize, isize);
+
+// Up, right, down and left.
+pub const ORTHOGONAL: [Dir; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// The orthogonal and the diagonal directions, clockwise from up.
+pub const DIRECTIONS: [Dir; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// A rectangle of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // The rows must have the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "ragged rows");
+        Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // One row on each line, see `parse::grid`.
+    pub fn parse<F>(lines: &[Line], cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        parse::grid(lines, cell).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // The cell one step away, if it is on the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): Dir) -> Option<Pos> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} is off the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // `chunks` doesn't take a zero size.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // All the cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&dir| self.offset(pos, dir))
+    }
+
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS
+            .iter()
+            .filter_map(move |&dir| self.offset(pos, dir))
+    }
+
+    // The cells in one direction up to the edge, not counting the start.
+    pub fn ray(&self, pos: Pos, dir: Dir) -> impl Iterator<Item = Pos> + '_ {
+        iter::successors(self.offset(pos, dir), move |&pos| self.offset(pos, dir))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Like `map`, with the position of each cell.
+    pub fn map_cells<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Pos, &T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+
+    // The grid with a margin of the value around it.
+    pub fn pad(&self, dx: usize, dy: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut padded = Self::filled(self.width + 2 * dx, self.height + 2 * dy, value);
+        for ((x, y), cell) in self.cells() {
+            padded[(x + dx, y + dy)] = cell.clone();
+        }
+        padded
+    }
+
+    // A part of the grid, which has to be inside it.
+    pub fn crop(&self, (x, y): Pos, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        assert!(x + width <= self.width && y + height <= self.height);
+        Self {
+            width,
+            height,
+            cells: (y..y + height)
+                .flat_map(|y| self.row(y)[x..x + width].iter().cloned())
+                .collect(),
+        }
+    }
+
+    //  -----      -----
+    // | abc |    | gda |
+    // | def | => | heb |
+    // | ghi |    | ifc |
+    //  -----      -----
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.height, self.width);
+        Self {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (y, self.height - 1 - x)))
+                .map(|pos| self[pos].clone())
+                .collect(),
+        }
+    }
+
+    //  -----      -----
+    // | abc |    | cba |
+    // | def | => | fed |
+    // | ghi |    | ihg |
+    //  -----      -----
+    pub fn flip(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .rows()
+                .flat_map(|row| row.iter().rev().cloned())
+                .collect(),
+        }
+    }
+
+    // The four rotations of the grid and of its mirror image.
+    pub fn transforms(&self) -> [Self; 8]
+    where
+        T: Clone,
+    {
+        let turn_1 = self.rotate();
+        let turn_2 = turn_1.rotate();
+        let turn_3 = turn_2.rotate();
+
+        let flip_0 = self.flip();
+        let flip_1 = flip_0.rotate();
+        let flip_2 = flip_1.rotate();
+        let flip_3 = flip_2.rotate();
+
+        [
+            self.clone(),
+            turn_1,
+            turn_2,
+            turn_3,
+            flip_0,
+            flip_1,
+            flip_2,
+            flip_3,
+        ]
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is off the {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is off the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+// One row on each line, the way the puzzles draw them.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop;
+    use crate::rng::Rng;
+    use std::collections::HashSet;
+
+    fn chars(s: &str) -> Grid<char> {
+        let lines: Vec<_> = parse::lines(s).collect();
+        Grid::parse(&lines, Some).unwrap()
+    }
+
+    fn grid(rng: &mut Rng) -> Grid<char> {
+        let (width, height) = (1 + rng.below(10), 1 + rng.below(10));
+        let cells = (0..width * height)
+            .map(|_| *rng.choose(&['.', '#']))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // The cells are all different, so no two transforms are the same.
+    fn labeled_grid(rng: &mut Rng) -> Grid<char> {
+        let size = 2 + rng.below(7);
+        let mut cells: Vec<char> = ('0'..='o').collect();
+        rng.shuffle(&mut cells);
+        cells.truncate(size * size);
+        Grid {
+            width: size,
+            height: size,
+            cells,
+        }
+    }
+
+    fn sorted(grid: &Grid<char>) -> Vec<char> {
+        let mut cells = grid.cells.clone();
+        cells.sort_unstable();
+        cells
+    }
+
+    #[test]
+    fn parsing() {
+        let grid = chars("abc\ndef\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let lines: Vec<_> = parse::lines("ab\nabc\n").collect();
+        let err = Grid::parse(&lines, Some).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: the grid rows differ in width");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = chars("abc\ndef\nghi\n");
+        let names = |cells: Vec<Pos>| -> String { cells.into_iter().map(|p| grid[p]).collect() };
+        assert_eq!(names(grid.neighbours_4((1, 1)).collect()), "bfhd");
+        assert_eq!(names(grid.neighbours_8((1, 1)).collect()), "bcfihgda");
+        assert_eq!(names(grid.neighbours_8((0, 0)).collect()), "bed");
+        assert_eq!(names(grid.ray((0, 0), (1, 1)).collect()), "ei");
+        assert_eq!(names(grid.ray((2, 1), (1, 0)).collect()), "");
+        assert_eq!(grid.offset((0, 2), (0, 1)), None);
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = chars("abc\ndef\n");
+        assert_eq!(grid.rotate().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.flip().to_string(), "cba\nfed\n");
+        assert_eq!(grid.pad(1, 0, '.').to_string(), ".abc.\n.def.\n");
+        assert_eq!(grid.crop((1, 0), 2, 2).to_string(), "bc\nef\n");
+
+        let all: Vec<String> = chars("ab\ncd\n")
+            .transforms()
+            .iter()
+            .map(|t| t.to_string().replace('\n', ""))
+            .collect();
+        assert_eq!(
+            all,
+            ["abcd", "cadb", "dcba", "bdac", "badc", "dbca", "cdab", "acbd"]
+        );
+    }
+
+    #[test]
+    fn transform_properties() {
+        prop::check("flip is an involution", grid, |g| g.flip().flip() == *g);
+        prop::check("four turns are no turn", grid, |g| {
+            g.rotate().rotate().rotate().rotate() == *g
+        });
+        prop::check("a flipped turn is an involution", grid, |g| {
+            let t = g.flip().rotate();
+            t.flip().rotate() == *g
+        });
+        prop::check("transforms keep the cells", grid, |g| {
+            g.transforms().iter().all(|t| sorted(t) == sorted(g))
+        });
+        prop::check("eight different transforms", labeled_grid, |g| {
+            let all: HashSet<Grid<char>> = g.transforms().iter().cloned().collect();
+            all.len() == 8
+        });
+        prop::check("the transforms are a group", labeled_grid, |g| {
+            let all: HashSet<Grid<char>> = g.transforms().iter().cloned().collect();
+            all.iter()
+                .all(|t| t.transforms().iter().all(|u| all.contains(u)))
+        });
+    }
+}
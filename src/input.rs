@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // The day's own file in the data directory.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    // A single dash stands for the standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+}
+
+fn read_file(path: &PathBuf) -> Result<String, Box<dyn Error>> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+// Reads the whole puzzle input as a text.
+pub fn read(source: &Source, default_path: &str) -> Result<String, Box<dyn Error>> {
+    match source {
+        Source::Default => {
+            let path = PathBuf::from(default_path);
+            if !path.exists() {
+                let err = format!("{} is missing, 'fetch' can download it", path.display());
+                return Err(err.into());
+            }
+            let input = read_file(&path)?;
+            if input.is_empty() {
+                let err = format!("{} is empty, 'fetch' can download it", path.display());
+                return Err(err.into());
+            }
+            Ok(input)
+        }
+        Source::File(path) => read_file(path),
+        Source::Stdin => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf)?;
+            Ok(buf)
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Solutions to the Advent of Code puzzles of several years.
+//
+// Every year has a module like `year_2020` with its days and their registry,
+// the shared infrastructure for parsing, grids, timing and reporting is at the top.
+// `YEARS` lists them all for the front ends.
+
+pub mod answers;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod pool;
+#[cfg(test)]
+mod prop;
+pub mod puzzle;
+pub mod report;
+pub mod rng;
+pub mod scaffold;
+pub mod timing;
+pub mod watch;
+pub mod year_2020;
+
+use input::Source;
+use puzzle::{Day, Part, Solution, Year};
+use std::error::Error;
+use std::panic;
+
+// The years are in order, the last one is the default.
+pub const YEARS: &[Year] = &[Year {
+    year: 2020,
+    days: year_2020::DAYS,
+    answers: "./data/2020/answers.toml",
+}];
+
+pub fn latest_year() -> u16 {
+    YEARS[YEARS.len() - 1].year
+}
+
+// The registry of a year.
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+// The registry entry of a day.
+pub fn day(year: u16, n: u8) -> Option<&'static Day> {
+    self::year(year)?.days.iter().find(|day| day.day == n)
+}
+
+// Reads the day's input and solves it. A panicking solver is reported as an error.
+pub fn solve_day(day: &Day, part: Part, source: &Source) -> Result<Solution, Box<dyn Error>> {
+    let input = input::read(source, day.input)?;
+
+    // A panic is reported by the default hook, so we only have to keep going.
+    panic::catch_unwind(|| (day.solve)(&input, part))
+        .unwrap_or_else(|_| Err("the solver panicked".into()))
+}
@@ -1,53 +1,343 @@
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
+mod cli;
+
+use aoc::answers::{self, Manifest};
+use aoc::fetch::Fetcher;
+use aoc::input::Source;
+use aoc::puzzle::{Answer, Answers, Day, Part, Solution};
+use aoc::report::{self, Format};
+use aoc::scaffold;
+use aoc::watch::Watcher;
+use aoc::year_2020::day_02::{self, AllOf};
+use aoc::year_2020::generate;
+use aoc::{input, pool, solve_day, timing};
+use cli::Command;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+// Returns the solution if the day has been solved without errors.
+fn report_day(day: &Day, result: Result<Solution, String>, format: Format) -> Option<Solution> {
+    if format == Format::Text {
+        println!("--- Day {}: {} ---", day.day, day.title);
+    }
+
+    match result {
+        Ok(solution) => {
+            if format == Format::Text {
+                if let Some(answer) = &solution.answers.part_1 {
+                    println!("Part 1. {}", answer);
+                }
+                if let Some(answer) = &solution.answers.part_2 {
+                    println!("Part 2. {}", answer);
+                }
+            }
+            Some(solution)
+        }
+        Err(err) => {
+            eprintln!("Day {} failed: {}", day.day, err);
+            None
+        }
+    }
+}
+
+// Returns true if every run of the day has succeeded.
+fn bench_day(day: &Day, part: Part, runs: usize) -> bool {
+    let mut timings = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        match solve_day(day, part, &Source::Default) {
+            Ok(solution) => timings.push(solution.timings),
+            Err(err) => {
+                eprintln!("Day {} failed: {}", day.day, err);
+                return false;
+            }
+        }
+    }
+
+    timing::print_bench_rows(day.day, &timings);
+    true
+}
+
+fn part_answer(answers: &Answers, part: Part) -> Option<&Answer> {
+    match part {
+        Part::One => answers.part_1.as_ref(),
+        _ => answers.part_2.as_ref(),
+    }
+}
+
+// Returns the number of the parts which didn't pass.
+fn verify_day(day: &Day, part: Part, expected: Option<&Answers>, time: bool) -> usize {
+    println!("--- Day {}: {} ---", day.day, day.title);
+
+    let mut failures = 0;
+    for (n, p) in [(1, Part::One), (2, Part::Two)] {
+        if (p == Part::One && !part.first()) || (p == Part::Two && !part.second()) {
+            continue;
+        }
+
+        let expected = expected.and_then(|answers| part_answer(answers, p));
+        match solve_day(day, p, &Source::Default) {
+            Ok(solution) => {
+                let took = if time {
+                    format!(" ({})", timing::format_duration(solution.timings.total()))
+                } else {
+                    String::new()
+                };
+                match (part_answer(&solution.answers, p), expected) {
+                    // The day has no such puzzle.
+                    (None, None) => continue,
+                    (Some(actual), Some(expected)) if actual == expected => {
+                        println!("Part {}. pass{}", n, took);
+                        continue;
+                    }
+                    (Some(actual), Some(expected)) => println!(
+                        "Part {}. mismatch: expected {}, got {}{}",
+                        n, expected, actual, took
+                    ),
+                    (Some(actual), None) => println!(
+                        "Part {}. unknown: {} is not in the manifest{}",
+                        n, actual, took
+                    ),
+                    (None, Some(expected)) => {
+                        println!("Part {}. fail: no answer, expected {}", n, expected)
+                    }
+                }
+            }
+            Err(err) => println!("Part {}. fail: {}", n, err),
+        }
+        failures += 1;
+    }
+    failures
+}
+
+// Verifies a day with its solver built from the current source.
+fn rebuild_and_verify(year: u16, day: u8, part: Part) {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let (day, year) = (day.to_string(), year.to_string());
+    let mut args = vec!["run", "--release", "--quiet", "--", "verify", &day];
+    args.extend(["--year", &year, "--time"]);
+    match part {
+        Part::One => args.extend(["--part", "1"]),
+        Part::Two => args.extend(["--part", "2"]),
+        Part::Both => (),
+    }
+
+    // The failures are reported by cargo and 'verify' themselves.
+    if let Err(err) = process::Command::new(&cargo).args(&args).status() {
+        eprintln!("Can't run {}: {}", cargo, err);
+    }
+}
+
+// The solved days of the year among the selected ones.
+fn select_days(year: u16, days: &[u8]) -> Vec<&'static Day> {
+    // The command line parser accepts known years only.
+    let year = aoc::year(year).expect("no such year");
+    year.days
+        .iter()
+        .filter(|day| days.contains(&day.day))
+        .collect()
+}
 
 fn main() {
-    day_01::run();
-    day_02::run();
-    day_03::run();
-    day_04::run();
-    day_05::run();
-    day_06::run();
-    day_07::run();
-    day_08::run();
-    day_09::run();
-    day_10::run();
-    day_11::run();
-    day_12::run();
-    day_13::run();
-    day_14::run();
-    day_15::run();
-    day_16::run();
-    day_17::run();
-    day_18::run();
-    day_19::run();
-    day_20::run();
-    day_21::run();
-    day_22::run();
-    day_23::run();
-    day_24::run();
-    day_25::run();
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", cli::USAGE),
+        Command::Run {
+            year,
+            days,
+            part,
+            input,
+            time,
+            format,
+            jobs,
+        } => {
+            let selected = select_days(year, &days);
+
+            let mut failures = 0;
+            let mut solved = Vec::new();
+            // Errors are turned into text to be sent between the threads.
+            pool::map_ordered(
+                &selected,
+                jobs,
+                |day| solve_day(day, part, &input).map_err(|err| err.to_string()),
+                |day, result| match report_day(day, result, format) {
+                    Some(solution) => solved.push((day.day, solution)),
+                    None => failures += 1,
+                },
+            );
+
+            let records = || {
+                solved
+                    .iter()
+                    .flat_map(|(day, solution)| report::records(*day, solution))
+                    .collect::<Vec<_>>()
+            };
+            match format {
+                Format::Text if time => {
+                    let rows: Vec<_> = solved.iter().map(|(day, s)| (*day, s.timings)).collect();
+                    println!();
+                    timing::print_table(&rows);
+                }
+                Format::Text => (),
+                Format::Json => println!("{}", report::to_json(&records())),
+                Format::Csv => print!("{}", report::to_csv(&records())),
+            }
+
+            if failures > 0 {
+                eprintln!("{} day(s) failed.", failures);
+                process::exit(1);
+            }
+        }
+        Command::Verify {
+            year,
+            days,
+            part,
+            time,
+        } => {
+            let path = aoc::year(year).expect("no such year").answers;
+            let manifest = Manifest::read(path).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
+
+            let failures: usize = select_days(year, &days)
+                .into_iter()
+                .map(|day| verify_day(day, part, manifest.get(day.day), time))
+                .sum();
+
+            if failures > 0 {
+                eprintln!("{} part(s) did not pass.", failures);
+                process::exit(1);
+            }
+        }
+        Command::Watch { year, day, part } => {
+            let day = aoc::day(year, day).unwrap_or_else(|| {
+                eprintln!(
+                    "Day {} of {} is not in the registry, 'new' can add it",
+                    day, year
+                );
+                process::exit(1);
+            });
+            let source = scaffold::source_path(Path::new("."), year, day.day);
+            if !source.exists() {
+                eprintln!(
+                    "{} is not found, 'watch' runs in the source tree",
+                    source.display()
+                );
+                process::exit(1);
+            }
+
+            let paths = [PathBuf::from(day.input), source];
+            let mut watcher = Watcher::new(&paths);
+            loop {
+                rebuild_and_verify(year, day.day, part);
+                println!("Watching {} and {}", paths[0].display(), paths[1].display());
+                for path in watcher.wait() {
+                    println!("\n{} has changed", path.display());
+                }
+            }
+        }
+        Command::Fetch { year, days, force } => {
+            let fetcher = Fetcher::from_env().unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
+
+            let mut failures = 0;
+            for day in select_days(year, &days) {
+                match fetcher.fetch(year, day.day, Path::new(day.input), force) {
+                    Ok(true) => println!("Day {}: downloaded {}", day.day, day.input),
+                    Ok(false) => println!("Day {}: {} is cached", day.day, day.input),
+                    Err(err) => {
+                        eprintln!("Day {} failed: {}", day.day, err);
+                        failures += 1;
+                    }
+                }
+            }
+
+            if failures > 0 {
+                eprintln!("{} day(s) failed.", failures);
+                process::exit(1);
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            // The day has been checked by the command line parser.
+            let generated = generate::generate(day, size, seed).expect("no generator");
+            print!("{}", generated.input);
+            // The answers are in the manifest format, ready for 'data/2020/answers.toml'.
+            eprintln!("[{}]", day);
+            if let Some(answer) = &generated.answers.part_1 {
+                eprintln!("part_1 = {}", answers::format_value(answer));
+            }
+            if let Some(answer) = &generated.answers.part_2 {
+                eprintln!("part_2 = {}", answers::format_value(answer));
+            }
+        }
+        Command::New { year, day, title } => {
+            match scaffold::scaffold(Path::new("."), year, day, &title) {
+                Ok(touched) => {
+                    for path in touched {
+                        println!("{}", path.display());
+                    }
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Audit { policies, input } => {
+            // The policies have been checked by the command line parser.
+            let policy = AllOf(
+                policies
+                    .iter()
+                    .map(|spec| day_02::policy(spec).expect("invalid policy"))
+                    .collect(),
+            );
+            // The source is a file or the standard input, there is no default path.
+            let dump = input::read(&input, "").unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
+
+            let audit = day_02::audit(&policy, &dump);
+            for finding in &audit.findings {
+                println!(
+                    "line {}: '{}' needs {}",
+                    finding.line,
+                    finding.password,
+                    finding.violations.join(", ")
+                );
+            }
+            let passed = audit.passwords - audit.findings.len();
+            println!("{} of {} passwords pass.", passed, audit.passwords);
+
+            if !audit.findings.is_empty() {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            year,
+            days,
+            part,
+            runs,
+        } => {
+            timing::print_bench_header(runs);
+            let failures = select_days(year, &days)
+                .into_iter()
+                .filter(|day| !bench_day(day, part, runs))
+                .count();
+
+            if failures > 0 {
+                eprintln!("{} day(s) failed.", failures);
+                process::exit(1);
+            }
+        }
+    }
 }
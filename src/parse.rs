@@ -0,0 +1,289 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+// A line of the input with its number. Lines are counted from 1.
+pub type Line<'a> = (usize, &'a str);
+
+// The lines of an input with their numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+// The lines which are not blank.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered_lines(input).filter(|(_, line)| !line.trim().is_empty())
+}
+
+// The paragraphs of an input. Groups are separated by one or more blank lines.
+pub fn groups(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut groups = Vec::new();
+    let mut next: Vec<Line> = Vec::new();
+    for (n, line) in numbered_lines(input) {
+        if line.trim().is_empty() {
+            if !next.is_empty() {
+                groups.push(std::mem::take(&mut next));
+            }
+        } else {
+            next.push((n, line));
+        }
+    }
+    if !next.is_empty() {
+        groups.push(next);
+    }
+    groups
+}
+
+// Splits at the first splitter and trims both sides.
+pub fn pair<'a>(s: &'a str, splitter: &str) -> Option<(&'a str, &'a str)> {
+    s.find(splitter)
+        .map(|pos| (s[0..pos].trim(), s[pos + splitter.len()..].trim()))
+}
+
+// Parses a number which is a part of a line.
+pub fn number<T>(line: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.parse()
+        .map_err(|err| ParseError::at(line, part, format!("invalid number '{}': {}", part, err)))
+}
+
+// One number on each line.
+pub fn numbers<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    lines(input)
+        .map(|(n, line)| number(line, line.trim()).map_err(|e| e.on_line(n)))
+        .collect()
+}
+
+// A list of numbers like "7,13,x,59", where the separator is given.
+// Empty entries are errors.
+pub fn number_list<T>(line: &str, part: &str, separator: char) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.split(separator)
+        .map(|s| number(line, s.trim()))
+        .collect()
+}
+
+// Whitespace separated "key:value" fields.
+pub fn key_values(line: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+    line.split_whitespace()
+        .map(|field| match field.split_once(':') {
+            Some((key, value)) if !key.is_empty() && !value.is_empty() => Ok((key, value)),
+            _ => Err(ParseError::at(line, field, "expected 'key:value'")),
+        })
+        .collect()
+}
+
+// A "key = value" line of a section, with its line to point at the errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub n: usize,
+    pub line: &'a str,
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+impl<'a> Entry<'a> {
+    // An error about a part of the entry.
+    pub fn error<S: Into<String>>(&self, part: &str, message: S) -> ParseError {
+        ParseError::at(self.line, part, message).on_line(self.n)
+    }
+
+    // The value between double quotes, None if it isn't quoted.
+    pub fn quoted(&self) -> Option<&'a str> {
+        self.value.strip_prefix('"')?.strip_suffix('"')
+    }
+
+    pub fn number<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        number(self.line, self.value).map_err(|e| e.on_line(self.n))
+    }
+}
+
+// A "[name]" header and the entries up to the next one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub n: usize,
+    pub line: &'a str,
+    pub name: &'a str,
+    pub entries: Vec<Entry<'a>>,
+}
+
+impl<'a> Section<'a> {
+    // An error about the name of the section.
+    pub fn error<S: Into<String>>(&self, message: S) -> ParseError {
+        ParseError::at(self.line, self.name, message).on_line(self.n)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Entry<'a>> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+}
+
+// The sections of a small subset of TOML: "[name]" headers followed by
+// "key = value" lines. Blank lines and "#" comments are skipped.
+pub fn sections(input: &str) -> Result<Vec<Section<'_>>, ParseError> {
+    let mut sections: Vec<Section> = Vec::new();
+    for (n, line) in lines(input) {
+        let t = line.trim();
+        if t.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = t.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .ok_or_else(|| ParseError::at(line, t, "unclosed section header").on_line(n))?;
+            sections.push(Section {
+                n,
+                line,
+                name: name.trim(),
+                entries: Vec::new(),
+            });
+            continue;
+        }
+
+        let (key, value) = pair(t, "=")
+            .ok_or_else(|| ParseError::at(line, t, "expected key = value").on_line(n))?;
+        let section = sections
+            .last_mut()
+            .ok_or_else(|| ParseError::at(line, t, "value outside of a section").on_line(n))?;
+        section.entries.push(Entry {
+            n,
+            line,
+            key,
+            value,
+        });
+    }
+    Ok(sections)
+}
+
+// A rectangle of characters, one row on each line. The cell function
+// decodes a character or returns None if it doesn't belong to the grid.
+pub fn grid<T, F>(lines: &[Line], cell: F) -> Result<Vec<Vec<T>>, ParseError>
+where
+    F: Fn(char) -> Option<T>,
+{
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for &(n, line) in lines {
+        let t = line.trim();
+        let mut row = Vec::with_capacity(t.len());
+        for (i, ch) in t.char_indices() {
+            let value = cell(ch).ok_or_else(|| {
+                let err = format!("unexpected character '{}'", ch);
+                ParseError::at(line, &t[i..], err).on_line(n)
+            })?;
+            row.push(value);
+        }
+        if rows.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(ParseError::new("the grid rows differ in width").on_line(n));
+        }
+        rows.push(row);
+    }
+    if rows.first().is_none_or(|first| first.is_empty()) {
+        return Err(ParseError::new("the grid is empty"));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitting() {
+        assert_eq!(pair("1-3 a: abcde", ":"), Some(("1-3 a", "abcde")));
+        assert_eq!(pair("mem[8] = 11", "="), Some(("mem[8]", "11")));
+        assert_eq!(pair("abc", "-"), None);
+
+        let input = "\nabc\n\na\nb\n  \n\nc\n";
+        let groups = groups(input);
+        assert_eq!(
+            groups,
+            vec![vec![(2, "abc")], vec![(4, "a"), (5, "b")], vec![(8, "c")]]
+        );
+        assert_eq!(lines(input).count(), 4);
+    }
+
+    #[test]
+    fn numbers_and_fields() {
+        assert_eq!(
+            numbers::<i64>("1721\n\n979\n-366\n"),
+            Ok(vec![1721, 979, -366])
+        );
+        let err = numbers::<i64>("1\n2\n 3x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(2)));
+
+        let line = "7,13,x";
+        assert_eq!(number_list::<u32>(line, &line[..4], ','), Ok(vec![7, 13]));
+        assert_eq!(
+            number_list::<u32>(line, line, ',').unwrap_err().column,
+            Some(6)
+        );
+
+        let line = "ecl:gry pid:860033327  eyr:2020";
+        assert_eq!(
+            key_values(line),
+            Ok(vec![("ecl", "gry"), ("pid", "860033327"), ("eyr", "2020")])
+        );
+        assert_eq!(key_values("ecl:gry hcl").unwrap_err().column, Some(9));
+    }
+
+    #[test]
+    fn grids() {
+        let tree = |ch| match ch {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        let lines = [(1, "..#"), (2, "#.."), (3, "   ")];
+        assert_eq!(
+            grid(&lines[..2], tree),
+            Ok(vec![vec![false, false, true], vec![true, false, false]])
+        );
+
+        let err = grid(&[(1, "..#"), (2, ".x.")], tree).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+        assert_eq!(
+            grid(&[(1, "..#"), (2, "..")], tree).unwrap_err().line,
+            Some(2)
+        );
+        assert!(grid(&lines[2..], tree).is_err());
+        assert!(grid(&[], tree).is_err());
+    }
+
+    #[test]
+    fn section_tables() {
+        let input = "# comment\n[one]\na = 1\nb = \"two\"\n\n[ two ]\n";
+        let sections = sections(input).unwrap();
+        assert_eq!(sections.len(), 2);
+        assert_eq!((sections[0].n, sections[0].name), (2, "one"));
+        assert_eq!(sections[0].get("a").map(|e| e.number()), Some(Ok(1)));
+        assert_eq!(sections[0].get("b").and_then(Entry::quoted), Some("two"));
+        assert_eq!(sections[0].get("c"), None);
+        assert_eq!((sections[1].name, sections[1].entries.len()), ("two", 0));
+
+        let error = |s: &str| super::sections(s).unwrap_err().to_string();
+        assert_eq!(
+            error("a = 1"),
+            "line 1, column 1: value outside of a section"
+        );
+        assert_eq!(error("[a\n"), "line 1, column 1: unclosed section header");
+        assert_eq!(
+            error("[a]\n  a 1"),
+            "line 2, column 3: expected key = value"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+// Maps the items on a number of threads. The results are handed over
+// in the order of the items as soon as all the preceding ones are ready.
+pub fn map_ordered<T, R, F, C>(items: &[T], threads: usize, f: F, mut consume: C)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    C: FnMut(&T, R),
+{
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        for item in items {
+            consume(item, f(item));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= items.len() {
+                    break;
+                }
+                if sender.send((index, f(&items[index]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        let mut first = 0;
+        for (index, result) in receiver {
+            pending[index] = Some(result);
+            while let Some(result) = pending.get_mut(first).and_then(Option::take) {
+                consume(&items[first], result);
+                first += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn ordered_results() {
+        let items: Vec<u64> = (0..20).collect();
+        for threads in [1, 3, 8] {
+            let mut results = Vec::new();
+            map_ordered(
+                &items,
+                threads,
+                |&n| {
+                    // The first items are the slowest ones.
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * n
+                },
+                |&n, square| results.push((n, square)),
+            );
+            let expected: Vec<(u64, u64)> = items.iter().map(|&n| (n, n * n)).collect();
+            assert_eq!(results, expected);
+        }
+    }
+}
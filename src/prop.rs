@@ -0,0 +1,55 @@
+// A tiny property testing harness. A property is checked on many cases made by
+// a generator, each case from its own seed. A failing case is reported with its
+// seed and can be replayed alone with PROP_SEED=<seed> cargo test <name>.
+use crate::rng::Rng;
+use std::env;
+use std::fmt::Debug;
+
+pub const CASES: usize = 256;
+const BASE_SEED: u64 = 2020;
+
+fn seeds() -> Vec<u64> {
+    if let Ok(seed) = env::var("PROP_SEED") {
+        return vec![seed.parse().expect("PROP_SEED is not a number")];
+    }
+    let mut rng = Rng::new(BASE_SEED);
+    (0..CASES).map(|_| rng.next_u64()).collect()
+}
+
+pub fn check<T, G, P>(name: &str, generate: G, property: P)
+where
+    T: Debug,
+    G: Fn(&mut Rng) -> T,
+    P: Fn(&T) -> bool,
+{
+    for seed in seeds() {
+        let case = generate(&mut Rng::new(seed));
+        if !property(&case) {
+            panic!(
+                "property '{}' fails for {:?} (PROP_SEED={})",
+                name, case, seed
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_have_seeds() {
+        check(
+            "addition commutes",
+            |rng| (rng.range(-9..=9), rng.range(-9..=9)),
+            |&(a, b)| a + b == b + a,
+        );
+
+        let failure = std::panic::catch_unwind(|| {
+            check("small numbers", |rng| rng.below(100), |&n| n < 90);
+        });
+        let message = *failure.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("property 'small numbers' fails for 9"));
+        assert!(message.contains("(PROP_SEED="));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::error::ParseError;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// Every puzzle has two parts; the second one unlocks after the first is solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn first(self) -> bool {
+        self != Part::Two
+    }
+
+    pub fn second(self) -> bool {
+        self != Part::One
+    }
+}
+
+// A puzzle answer is either a number or a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+// Each day parses its puzzle input once and then solves both parts on it.
+pub trait Solver {
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    // The last day of the calendar has only one puzzle.
+    const HAS_PART_2: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>>;
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>>;
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+}
+
+// How long each step of a solution took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_1.unwrap_or_default() + self.part_2.unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Solution {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<Solution, Box<dyn Error>> {
+    let mut solution = Solution::default();
+
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    solution.timings.parse = start.elapsed();
+
+    if part.first() {
+        let start = Instant::now();
+        solution.answers.part_1 = Some(S::part_1(&input)?.into());
+        solution.timings.part_1 = Some(start.elapsed());
+    }
+    if part.second() && S::HAS_PART_2 {
+        let start = Instant::now();
+        solution.answers.part_2 = Some(S::part_2(&input)?.into());
+        solution.timings.part_2 = Some(start.elapsed());
+    }
+    Ok(solution)
+}
+
+// Solves a puzzle example in the tests.
+#[cfg(test)]
+pub fn solve_example<S: Solver>(input: &str, part: Part) -> Answers {
+    match solve::<S>(input, part) {
+        Ok(solution) => solution.answers,
+        Err(err) => panic!("the example can't be solved: {}", err),
+    }
+}
+
+pub type SolveFn = fn(&str, Part) -> Result<Solution, Box<dyn Error>>;
+
+// An entry of the day registry.
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    // The default input file.
+    pub input: &'static str,
+    pub solve: SolveFn,
+}
+
+// The days of a year which have been solved, with the manifest of their answers.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+    pub answers: &'static str,
+}
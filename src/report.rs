@@ -0,0 +1,159 @@
+use crate::puzzle::{Answer, Solution};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+// One answer in a machine readable report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub fn records(day: u8, solution: &Solution) -> Vec<Record> {
+    let parts = [
+        (1, &solution.answers.part_1, solution.timings.part_1),
+        (2, &solution.answers.part_2, solution.timings.part_2),
+    ];
+
+    let mut buf = Vec::new();
+    for (part, answer, elapsed) in parts.iter() {
+        if let Some(answer) = answer {
+            buf.push(Record {
+                day,
+                part: *part,
+                answer: answer.clone(),
+                elapsed: elapsed.unwrap_or_default(),
+            });
+        }
+    }
+    buf
+}
+
+fn json_string(s: &str) -> String {
+    let mut buf = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c.is_control() => buf.push_str(&format!("\\u{:04x}", c as u32)),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+    buf
+}
+
+// The elapsed time is given in seconds.
+pub fn to_json(records: &[Record]) -> String {
+    let items: Vec<String> = records
+        .iter()
+        .map(|r| {
+            let answer = match &r.answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(s) => json_string(s),
+            };
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {:.6}}}",
+                r.day,
+                r.part,
+                answer,
+                r.elapsed.as_secs_f64()
+            )
+        })
+        .collect();
+
+    if items.is_empty() {
+        String::from("[]")
+    } else {
+        format!("[\n{}\n]", items.join(",\n"))
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+// The elapsed time is given in seconds.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut buf = String::from("day,part,answer,elapsed\n");
+    for r in records {
+        buf.push_str(&format!(
+            "{},{},{},{:.6}\n",
+            r.day,
+            r.part,
+            csv_field(&r.answer.to_string()),
+            r.elapsed.as_secs_f64()
+        ));
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: 1,
+                answer: Answer::Number(514579),
+                elapsed: Duration::from_micros(1500),
+            },
+            Record {
+                day: 21,
+                part: 2,
+                answer: Answer::Text(String::from("mxmxvkd,sqjhc,\"fvjkl\"")),
+                elapsed: Duration::from_secs(2),
+            },
+        ]
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            to_json(&sample()),
+            r#"[
+  {"day": 1, "part": 1, "answer": 514579, "elapsed": 0.001500},
+  {"day": 21, "part": 2, "answer": "mxmxvkd,sqjhc,\"fvjkl\"", "elapsed": 2.000000}
+]"#
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            to_csv(&sample()),
+            "day,part,answer,elapsed\n\
+             1,1,514579,0.001500\n\
+             21,2,\"mxmxvkd,sqjhc,\"\"fvjkl\"\"\",2.000000\n"
+        );
+    }
+}
@@ -0,0 +1,482 @@
+// Adds a new day to the tree: a solver skeleton with an example test stub,
+// its registry entry, an input placeholder and a calendar link in the README.
+// A day of a new year brings the year module along.
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+pub const FIRST_YEAR: u16 = 2015;
+pub const DEFAULT_TITLE: &str = "Untitled";
+
+const SOLVER: &str = r#"use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+fn read_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(parse::lines(input).map(|(_, s)| s.trim().to_string()).collect())
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_1(_lines: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Err("part 1 is not solved yet".into())
+    }
+
+    // --- Part Two ---
+    fn part_2(_lines: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Err("part 2 is not solved yet".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+";
+
+    // Fill in the example of the puzzle and its answers, then drop the ignore.
+    #[test]
+    #[ignore]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(0)));
+        assert_eq!(answers.part_2, Some(Answer::Number(0)));
+    }
+}
+"#;
+
+fn year_module(year: u16) -> String {
+    format!(
+        "// The Advent of Code {} puzzles.
+//
+// Each day module has a `Puzzle` implementing `puzzle::Solver` next to its
+// domain types, and `DAYS` lists them all for the front ends.
+
+use crate::puzzle::{{self, Day}};
+
+pub const DAYS: &[Day] = &[];
+",
+        year
+    )
+}
+
+fn manifest(year: u16) -> String {
+    format!(
+        "# The accepted answers to our {} puzzle inputs, checked by `cargo run --release -- verify`.\n",
+        year
+    )
+}
+
+// An entry of a registry list is a list of fields.
+type Entry = Vec<(String, String)>;
+
+// The position of the pattern in Rust code, skipping over string literals,
+// so a title like "{x}];" doesn't count.
+fn find_code(text: &str, pattern: &str) -> Option<usize> {
+    let mut string = false;
+    let mut escaped = false;
+    for (i, ch) in text.char_indices() {
+        if string {
+            match ch {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => string = false,
+                _ => (),
+            }
+        } else if text[i..].starts_with(pattern) {
+            return Some(i);
+        } else if ch == '"' {
+            string = true;
+        }
+    }
+    None
+}
+
+// Finds a registry list like `pub const DAYS: &[Day] = &[...];` and returns
+// the range of its entries. An entry has one field on each line.
+fn read_registry(
+    text: &str,
+    name: &str,
+    kind: &str,
+) -> Result<(Range<usize>, Vec<Entry>), Box<dyn Error>> {
+    let head = format!("pub const {}: &[{}] = &[", name, kind);
+    let start = text
+        .find(&head)
+        .map(|pos| pos + head.len())
+        .ok_or_else(|| format!("'{}' is not found", head))?;
+    let end = find_code(&text[start..], "];")
+        .map(|pos| start + pos)
+        .ok_or_else(|| format!("the end of {} is not found", name))?;
+
+    let mut entries = Vec::new();
+    let mut rest = &text[start..end];
+    while let Some(open) = find_code(rest, "{") {
+        let close = find_code(&rest[open..], "}")
+            .map(|pos| open + pos)
+            .ok_or_else(|| format!("an entry of {} is not closed", name))?;
+        let fields = rest[open + 1..close]
+            .lines()
+            .filter_map(|line| line.trim().trim_end_matches(',').split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+        entries.push(fields);
+        rest = &rest[close + 1..];
+    }
+    Ok((start..end, entries))
+}
+
+fn field<'a>(entry: &'a Entry, key: &str) -> Option<&'a str> {
+    entry
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value.as_str())
+}
+
+// Adds an entry to a registry list, the entries are ordered by the key field.
+fn add_entry(
+    text: &str,
+    name: &str,
+    kind: &str,
+    key: &str,
+    entry: Entry,
+) -> Result<String, Box<dyn Error>> {
+    let (range, mut entries) = read_registry(text, name, kind)?;
+    let number = |entry: &Entry| field(entry, key).and_then(|value| value.parse::<u16>().ok());
+    let new = number(&entry).ok_or_else(|| format!("the new entry has no {}", key))?;
+    if entries.iter().any(|e| number(e) == Some(new)) {
+        return Err(format!("{} {} is in {} already", key, new, name).into());
+    }
+    entries.push(entry);
+    entries.sort_by_key(|e| number(e));
+
+    let mut list = String::from("\n");
+    for entry in &entries {
+        list.push_str(&format!("    {} {{\n", kind));
+        for (key, value) in entry {
+            list.push_str(&format!("        {}: {},\n", key, value));
+        }
+        list.push_str("    },\n");
+    }
+    Ok(format!(
+        "{}{}{}",
+        &text[..range.start],
+        list,
+        &text[range.end..]
+    ))
+}
+
+// Adds a day to the `DAYS` registry of a year module.
+pub fn add_day_entry(
+    module: &str,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<String, Box<dyn Error>> {
+    let entry = vec![
+        (String::from("day"), day.to_string()),
+        (String::from("title"), format!("{:?}", title)),
+        (
+            String::from("input"),
+            format!("\"./data/{}/day-{:02}.txt\"", year, day),
+        ),
+        (
+            String::from("solve"),
+            format!("puzzle::solve::<day_{:02}::Puzzle>", day),
+        ),
+    ];
+    add_entry(module, "DAYS", "Day", "day", entry)
+}
+
+// Adds a year to the `YEARS` registry of the crate root.
+pub fn add_year_entry(lib: &str, year: u16) -> Result<String, Box<dyn Error>> {
+    let entry = vec![
+        (String::from("year"), year.to_string()),
+        (String::from("days"), format!("year_{}::DAYS", year)),
+        (
+            String::from("answers"),
+            format!("\"./data/{}/answers.toml\"", year),
+        ),
+    ];
+    add_entry(lib, "YEARS", "Year", "year", entry)
+}
+
+// Declares a public module among the others, in the alphabetical order.
+pub fn add_module(text: &str, name: &str) -> Result<String, Box<dyn Error>> {
+    let declaration = format!("pub mod {};", name);
+    if text.lines().any(|line| line == declaration) {
+        return Err(format!("module {} is declared already", name).into());
+    }
+
+    let mut lines: Vec<&str> = text.lines().collect();
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod "))
+        .collect();
+    let at = match (
+        modules.iter().find(|&&i| lines[i] > declaration.as_str()),
+        modules.last(),
+    ) {
+        (Some(&i), _) => i,
+        (None, Some(&i)) => i + 1,
+        // The first module goes ahead of the imports.
+        (None, None) => lines
+            .iter()
+            .position(|line| line.starts_with("use "))
+            .unwrap_or(lines.len()),
+    };
+    lines.insert(at, &declaration);
+    // A module ahead of the code has to be apart from it.
+    if modules.is_empty() && lines.get(at + 1).is_some_and(|line| !line.is_empty()) {
+        lines.insert(at + 1, "");
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds a link to the calendar of the year in the README, the days stay in order.
+pub fn add_calendar_link(readme: &str, year: u16, day: u8, title: &str) -> String {
+    let header = format!("## Calendar {}", year);
+    let link = format!(
+        "* [Day {}: {}](./src/year_{}/day_{:02}.rs)",
+        day, title, year, day
+    );
+    let day_of = |line: &str| -> Option<u8> {
+        let rest = line.strip_prefix("* [Day ")?;
+        rest[..rest.find(':')?].parse().ok()
+    };
+
+    let mut lines: Vec<&str> = readme.lines().collect();
+    match lines.iter().position(|line| *line == header) {
+        Some(pos) => {
+            let mut at = pos + 1;
+            while lines
+                .get(at)
+                .and_then(|line| day_of(line))
+                .is_some_and(|d| d < day)
+            {
+                at += 1;
+            }
+            lines.insert(at, &link);
+        }
+        None => {
+            lines.extend(["", header.as_str(), link.as_str()]);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+// The registry lists are written out in full, so rustfmt tidies them up if it is there.
+// It reads the standard input to leave the declared modules alone.
+fn tidy(text: String) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2018", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let output = child.and_then(|mut child| {
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        child.wait_with_output()
+    });
+    match output {
+        Ok(output) if output.status.success() => String::from_utf8(output.stdout).unwrap_or(text),
+        _ => text,
+    }
+}
+
+fn update<F>(path: &Path, change: F) -> Result<(), Box<dyn Error>>
+where
+    F: FnOnce(&str) -> Result<String, Box<dyn Error>>,
+{
+    let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let mut text = change(&text).map_err(|err| format!("{}: {}", path.display(), err))?;
+    // Only the Rust sources are tidied, the README is written as it is.
+    if path.extension().is_some_and(|ext| ext == "rs") {
+        text = tidy(text);
+    }
+    fs::write(path, text).map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+fn create(path: &Path, text: &str) -> Result<(), Box<dyn Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, text).map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+// The source file of a day in the tree at the root.
+pub fn source_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join("src")
+        .join(format!("year_{}", year))
+        .join(format!("day_{:02}.rs", day))
+}
+
+// Scaffolds a day in the tree at the root and returns the files it has touched.
+pub fn scaffold(
+    root: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let year_dir = root.join("src").join(format!("year_{}", year));
+    let source = source_path(root, year, day);
+    if source.exists() {
+        return Err(format!("{} exists already", source.display()).into());
+    }
+
+    let mut touched = Vec::new();
+    let module = year_dir.join("mod.rs");
+    if !module.exists() {
+        let lib = root.join("src").join("lib.rs");
+        update(&lib, |text| {
+            add_year_entry(&add_module(text, &format!("year_{}", year))?, year)
+        })?;
+        create(&module, &year_module(year))?;
+        touched.push(lib);
+    }
+    update(&module, |text| {
+        add_day_entry(
+            &add_module(text, &format!("day_{:02}", day))?,
+            year,
+            day,
+            title,
+        )
+    })?;
+    create(&source, SOLVER)?;
+    touched.push(module);
+    touched.push(source.clone());
+
+    // The placeholders are empty, so 'fetch' still downloads the input.
+    let data = root.join("data").join(year.to_string());
+    let input = data.join(format!("day-{:02}.txt", day));
+    if !input.exists() {
+        create(&input, "")?;
+        touched.push(input);
+    }
+    let answers = data.join("answers.toml");
+    if !answers.exists() {
+        create(&answers, &manifest(year))?;
+        touched.push(answers);
+    }
+
+    let readme = root.join("README.md");
+    if readme.exists() {
+        update(&readme, |text| {
+            Ok(add_calendar_link(text, year, day, title))
+        })?;
+        touched.push(readme);
+    }
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const MODULE: &str = "\
+pub mod day_01;
+pub mod day_03;
+pub mod generate;
+
+use crate::puzzle::{self, Day};
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        title: \"Report Repair\",
+        input: \"./data/2020/day-01.txt\",
+        solve: puzzle::solve::<day_01::Puzzle>,
+    },
+    Day {
+        day: 3,
+        title: \"Toboggan Trajectory\",
+        input: \"./data/2020/day-03.txt\",
+        solve: puzzle::solve::<day_03::Puzzle>,
+    },
+];
+";
+
+    #[test]
+    fn registry_edits() {
+        let module = add_module(MODULE, "day_02").unwrap();
+        assert!(module.starts_with("pub mod day_01;\npub mod day_02;\npub mod day_03;\n"));
+        let module = add_day_entry(&module, 2020, 2, "Password \"Philosophy\"").unwrap();
+        let (_, entries) = read_registry(&module, "DAYS", "Day").unwrap();
+        let days: Vec<&str> = entries.iter().filter_map(|e| field(e, "day")).collect();
+        assert_eq!(days, ["1", "2", "3"]);
+        assert_eq!(
+            field(&entries[1], "title"),
+            Some("\"Password \\\"Philosophy\\\"\"")
+        );
+        assert!(add_day_entry(&module, 2020, 3, "Again").is_err());
+        assert!(add_module(&module, "day_02").is_err());
+
+        // Braces and brackets in a title are inside of its string.
+        let braces = add_day_entry(&module, 2020, 4, "Odd { Braces }]; \\\" }").unwrap();
+        let braces = add_day_entry(&braces, 2020, 5, "After").unwrap();
+        let (_, entries) = read_registry(&braces, "DAYS", "Day").unwrap();
+        let days: Vec<&str> = entries.iter().filter_map(|e| field(e, "day")).collect();
+        assert_eq!(days, ["1", "2", "3", "4", "5"]);
+        assert_eq!(
+            field(&entries[3], "title"),
+            Some(r#""Odd { Braces }]; \\\" }""#)
+        );
+        assert!(braces.ends_with("    },\n];\n"));
+
+        let lib = "pub mod puzzle;\npub mod year_2020;\n\npub const YEARS: &[Year] = &[Year {\n    year: 2020,\n    days: year_2020::DAYS,\n}];\n";
+        let lib = add_year_entry(&add_module(lib, "year_2021").unwrap(), 2021).unwrap();
+        assert!(lib.contains("pub mod year_2020;\npub mod year_2021;\n"));
+        let (_, entries) = read_registry(&lib, "YEARS", "Year").unwrap();
+        assert_eq!(field(&entries[1], "days"), Some("year_2021::DAYS"));
+
+        let readme = "# AoC\n\n## Calendar 2020\n* [Day 1: A](./src/year_2020/day_01.rs)\n* [Day 3: C](./src/year_2020/day_03.rs)\n";
+        let readme = add_calendar_link(readme, 2020, 2, "B");
+        assert!(readme.contains("day_01.rs)\n* [Day 2: B](./src/year_2020/day_02.rs)\n* [Day 3"));
+        assert!(add_calendar_link(&readme, 2021, 1, "D")
+            .ends_with("\n\n## Calendar 2021\n* [Day 1: D](./src/year_2021/day_01.rs)\n"));
+    }
+
+    #[test]
+    fn new_year() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        create(
+            &root.join("src").join("lib.rs"),
+            "pub mod puzzle;\n\npub const YEARS: &[Year] = &[];\n",
+        )
+        .unwrap();
+
+        let touched = scaffold(&root, 2021, 1, "Sonar Sweep").unwrap();
+        assert_eq!(touched.len(), 5);
+        let module = fs::read_to_string(root.join("src/year_2021/mod.rs")).unwrap();
+        assert!(module.contains("pub mod day_01;"));
+        assert!(module.contains("Sonar Sweep"));
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("year_2021::DAYS"));
+        assert_eq!(
+            fs::read_to_string(root.join("data/2021/day-01.txt")).unwrap(),
+            ""
+        );
+        assert!(scaffold(&root, 2021, 1, "Sonar Sweep").is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
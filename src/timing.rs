@@ -0,0 +1,120 @@
+use crate::puzzle::Timings;
+use std::time::Duration;
+
+pub fn format_duration(d: Duration) -> String {
+    format!("{:.3} ms", d.as_secs_f64() * 1000.0)
+}
+
+fn format_cell(d: Option<Duration>) -> String {
+    d.map(format_duration).unwrap_or_else(|| String::from("-"))
+}
+
+pub fn print_table(rows: &[(u8, Timings)]) {
+    println!(
+        "{:>5} {:>14} {:>14} {:>14} {:>14}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for (day, timings) in rows {
+        println!(
+            "{:>5} {:>14} {:>14} {:>14} {:>14}",
+            day,
+            format_duration(timings.parse),
+            format_cell(timings.part_1),
+            format_cell(timings.part_2),
+            format_duration(timings.total())
+        );
+    }
+
+    let total: Duration = rows.iter().map(|(_, timings)| timings.total()).sum();
+    println!(
+        "{:>5} {:>14} {:>14} {:>14} {:>14}",
+        "All",
+        "",
+        "",
+        "",
+        format_duration(total)
+    );
+}
+
+// The spread of repeated measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+
+        Some(Self {
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        })
+    }
+}
+
+pub fn print_bench_header(runs: usize) {
+    println!("{} run(s) per day", runs);
+    println!(
+        "{:>5} {:>8} {:>14} {:>14} {:>14}",
+        "Day", "Step", "Min", "Median", "Max"
+    );
+}
+
+pub fn print_bench_rows(day: u8, runs: &[Timings]) {
+    // A step is missing if the part has not been solved.
+    let steps: [(&str, Option<Vec<Duration>>); 4] = [
+        ("parse", runs.iter().map(|t| Some(t.parse)).collect()),
+        ("part 1", runs.iter().map(|t| t.part_1).collect()),
+        ("part 2", runs.iter().map(|t| t.part_2).collect()),
+        ("total", runs.iter().map(|t| Some(t.total())).collect()),
+    ];
+
+    let mut label = day.to_string();
+    for (name, samples) in steps {
+        if let Some(stats) = samples.and_then(Stats::new) {
+            println!(
+                "{:>5} {:>8} {:>14} {:>14} {:>14}",
+                label,
+                name,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max)
+            );
+            label.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+
+        let stats = Stats::new(vec![ms(4), ms(1), ms(2), ms(8)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+
+        assert_eq!(Stats::new(Vec::new()), None);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// What tells a file has changed. A missing file has no stamp.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &PathBuf) -> Stamp {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+// Polls some files for changes. There is no file system notification
+// in the standard library, and a few files are cheap to poll.
+#[derive(Debug)]
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    pub fn new(paths: &[PathBuf]) -> Self {
+        Self {
+            files: paths
+                .iter()
+                .map(|path| (path.clone(), stamp(path)))
+                .collect(),
+        }
+    }
+
+    // The files which have changed since the last look.
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in &mut self.files {
+            let now = stamp(path);
+            if now != *last {
+                *last = now;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    // Waits until some of the files change.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            let changed = self.changes();
+            if !changed.is_empty() {
+                return changed;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn changes() {
+        let path = env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let missing = path.with_extension("missing");
+        fs::write(&path, "1721\n").unwrap();
+
+        let mut watcher = Watcher::new(&[path.clone(), missing.clone()]);
+        assert!(watcher.changes().is_empty());
+
+        fs::write(&path, "1721\n979\n").unwrap();
+        assert_eq!(watcher.changes(), vec![path.clone()]);
+        assert!(watcher.changes().is_empty());
+
+        fs::write(&missing, "").unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.wait(), vec![path, missing.clone()]);
+        fs::remove_file(&missing).unwrap();
+    }
+}
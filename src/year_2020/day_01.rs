@@ -0,0 +1,277 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+
+// The indices of k entries which sum to the target, in ascending order.
+// Each entry is used at most once.
+pub fn find_k_sum(entries: &[usize], k: usize, target: usize) -> Option<Vec<usize>> {
+    if k == 2 {
+        return find_pair(entries, target);
+    }
+    let mut first = None;
+    k_sums(entries, k, target, &mut |indices: &[usize]| {
+        first = Some(indices.to_vec());
+        false
+    });
+    first
+}
+
+// All the sets of k entries which sum to the target, as sorted index tuples in sorted order.
+pub fn find_all_k_sums(entries: &[usize], k: usize, target: usize) -> Vec<Vec<usize>> {
+    let mut all = Vec::new();
+    k_sums(entries, k, target, &mut |indices: &[usize]| {
+        all.push(indices.to_vec());
+        true
+    });
+    all.sort_unstable();
+    all
+}
+
+// One pass, looking up the rest of the target among the entries seen so far.
+fn find_pair(entries: &[usize], target: usize) -> Option<Vec<usize>> {
+    let mut seen = HashMap::new();
+    for (j, &entry) in entries.iter().enumerate() {
+        if let Some(&i) = target.checked_sub(entry).and_then(|rest| seen.get(&rest)) {
+            return Some(vec![i, j]);
+        }
+        seen.entry(entry).or_insert(j);
+    }
+    None
+}
+
+// Calls visit with the indices of each set of k entries summing to the target,
+// until it returns false.
+fn k_sums<F>(entries: &[usize], k: usize, target: usize, visit: &mut F)
+where
+    F: FnMut(&[usize]) -> bool,
+{
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i]);
+    let mut search = Search {
+        values: order.iter().map(|&i| entries[i]).collect(),
+        order,
+        chosen: Vec::with_capacity(k),
+        visit,
+    };
+    search.run(0, k, target);
+}
+
+// A search over the sorted entries. The positions are in the sorted values,
+// `order` maps them back to the indices of the entries.
+struct Search<'a, F> {
+    values: Vec<usize>,
+    order: Vec<usize>,
+    chosen: Vec<usize>,
+    visit: &'a mut F,
+}
+
+impl<F> Search<'_, F>
+where
+    F: FnMut(&[usize]) -> bool,
+{
+    fn found(&mut self, last: &[usize]) -> bool {
+        let mut indices: Vec<usize> = self
+            .chosen
+            .iter()
+            .chain(last)
+            .map(|&p| self.order[p])
+            .collect();
+        indices.sort_unstable();
+        (self.visit)(&indices)
+    }
+
+    // Picks k of the values from the start position on. Returns false when the visit is over.
+    fn run(&mut self, start: usize, k: usize, target: usize) -> bool {
+        match k {
+            0 => target != 0 || self.found(&[]),
+            1 => {
+                let values = &self.values[start..];
+                let lo = start + values.partition_point(|&v| v < target);
+                let hi = start + values.partition_point(|&v| v <= target);
+                (lo..hi).all(|p| self.found(&[p]))
+            }
+            2 => self.pairs(start, target),
+            _ => {
+                for p in start..self.values.len() {
+                    // The values only grow, so k of them are too much from here on.
+                    let value = self.values[p];
+                    if value.saturating_mul(k) > target {
+                        break;
+                    }
+                    self.chosen.push(p);
+                    let more = self.run(p + 1, k - 1, target - value);
+                    self.chosen.pop();
+                    if !more {
+                        return false;
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    // Two pointers closing in from both ends. Equal values come in runs,
+    // and every value of a run pairs with every value of the matching run.
+    fn pairs(&mut self, start: usize, target: usize) -> bool {
+        let (mut lo, mut hi) = (start, self.values.len());
+        while lo + 1 < hi {
+            let (a, b) = (self.values[lo], self.values[hi - 1]);
+            let rest = match target.checked_sub(a) {
+                Some(rest) => rest,
+                None => break,
+            };
+            if b < rest {
+                lo += 1;
+            } else if b > rest {
+                hi -= 1;
+            } else if a == b {
+                // All the values in between are the same.
+                for i in lo..hi {
+                    for j in i + 1..hi {
+                        if !self.found(&[i, j]) {
+                            return false;
+                        }
+                    }
+                }
+                break;
+            } else {
+                let lo_end = lo + self.values[lo..hi].partition_point(|&v| v == a);
+                let hi_start = lo_end + self.values[lo_end..hi].partition_point(|&v| v < b);
+                for i in lo..lo_end {
+                    for j in hi_start..hi {
+                        if !self.found(&[i, j]) {
+                            return false;
+                        }
+                    }
+                }
+                lo = lo_end;
+                hi = hi_start;
+            }
+        }
+        true
+    }
+}
+
+fn product(entries: &[usize], indices: &[usize]) -> usize {
+    indices.iter().map(|&i| entries[i]).product()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::numbers(input)
+    }
+
+    // Find the two entries that sum to 2020 and then multiply those two numbers together.
+    fn part_1(expense_report: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        if let Some(indices) = find_k_sum(expense_report, 2, 2020) {
+            // Your puzzle answer was 1019371.
+            return Ok(product(expense_report, &indices));
+        }
+        Err("no two entries sum to 2020".into())
+    }
+
+    // --- Part Two ---
+
+    // What is the product of the three entries that sum to 2020?
+    fn part_2(expense_report: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        if let Some(indices) = find_k_sum(expense_report, 3, 2020) {
+            // Your puzzle answer was 278064990.
+            return Ok(product(expense_report, &indices));
+        }
+        Err("no three entries sum to 2020".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop;
+    use crate::puzzle::{solve_example, Answer, Part};
+    use crate::rng::Rng;
+
+    const EXAMPLE: &str = "\
+1721
+979
+366
+299
+675
+1456
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(514579)));
+        assert_eq!(answers.part_2, Some(Answer::Number(241861950)));
+    }
+
+    #[test]
+    fn k_sums() {
+        let report = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(find_k_sum(&report, 2, 2020), Some(vec![0, 3]));
+        assert_eq!(find_k_sum(&report, 3, 2020), Some(vec![1, 2, 4]));
+        assert_eq!(find_k_sum(&report, 4, 2020), None);
+        assert_eq!(find_k_sum(&report, 1, 979), Some(vec![1]));
+        assert_eq!(find_k_sum(&report, 0, 0), Some(vec![]));
+        assert_eq!(find_k_sum(&report, 7, 5496), None);
+
+        // Equal entries make different sets.
+        let ones = [1, 1, 2, 1];
+        assert_eq!(find_all_k_sums(&ones, 2, 2), [[0, 1], [0, 3], [1, 3]]);
+        assert_eq!(
+            find_all_k_sums(&ones, 3, 4),
+            [[0, 1, 2], [0, 2, 3], [1, 2, 3]]
+        );
+    }
+
+    // The sets of k indices below n, the slow way.
+    fn subsets(n: usize, k: usize) -> Vec<Vec<usize>> {
+        if k == 0 {
+            return vec![vec![]];
+        }
+        (k - 1..n)
+            .flat_map(|last| {
+                subsets(last, k - 1).into_iter().map(move |mut set| {
+                    set.push(last);
+                    set
+                })
+            })
+            .collect()
+    }
+
+    fn report(rng: &mut Rng) -> (Vec<usize>, usize, usize) {
+        let entries: Vec<usize> = (0..rng.below(12)).map(|_| rng.below(20)).collect();
+        (entries, rng.below(5), rng.below(50))
+    }
+
+    #[test]
+    fn k_sum_properties() {
+        prop::check("all the sets are found", report, |(entries, k, target)| {
+            let mut sets: Vec<Vec<usize>> = subsets(entries.len(), *k)
+                .into_iter()
+                .filter(|set| set.iter().map(|&i| entries[i]).sum::<usize>() == *target)
+                .collect();
+            sets.sort_unstable();
+            find_all_k_sums(entries, *k, *target) == sets
+        });
+        prop::check(
+            "the first set is one of them",
+            report,
+            |(entries, k, target)| {
+                let all = find_all_k_sums(entries, *k, *target);
+                match find_k_sum(entries, *k, *target) {
+                    Some(set) => all.contains(&set),
+                    None => all.is_empty(),
+                }
+            },
+        );
+    }
+}
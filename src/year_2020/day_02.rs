@@ -0,0 +1,825 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+// A rule for passwords. The policies of a dump audit are picked by name with `policy`.
+pub trait PasswordPolicy {
+    fn allows(&self, password: &str) -> bool;
+
+    // What the policy asks for, e.g. "at least 12 characters".
+    fn describe(&self) -> String;
+
+    // The requirements the password misses, none if it is allowed.
+    fn violations(&self, password: &str) -> Vec<String> {
+        if self.allows(password) {
+            Vec::new()
+        } else {
+            vec![self.describe()]
+        }
+    }
+}
+
+// The letter must appear from min to max times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterCount {
+    pub letter: char,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl PasswordPolicy for LetterCount {
+    fn allows(&self, password: &str) -> bool {
+        let count = password.chars().filter(|&ch| ch == self.letter).count();
+        count >= self.min && count <= self.max
+    }
+
+    fn describe(&self) -> String {
+        format!("'{}' {} to {} times", self.letter, self.min, self.max)
+    }
+}
+
+// Exactly one of the two positions must contain the letter.
+// Positions are counted from 1, there is no concept of "index zero".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterPositions {
+    pub letter: char,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl PasswordPolicy for LetterPositions {
+    fn allows(&self, password: &str) -> bool {
+        let at = |position: usize| {
+            position > 0 && password.chars().nth(position - 1) == Some(self.letter)
+        };
+        at(self.first) != at(self.second)
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "'{}' at exactly one of the positions {} and {}",
+            self.letter, self.first, self.second
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinLength(pub usize);
+
+impl PasswordPolicy for MinLength {
+    fn allows(&self, password: &str) -> bool {
+        password.chars().count() >= self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("at least {} characters", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+}
+
+impl CharClass {
+    pub fn values() -> [CharClass; 4] {
+        [
+            CharClass::Lower,
+            CharClass::Upper,
+            CharClass::Digit,
+            CharClass::Symbol,
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            CharClass::Lower => "lower",
+            CharClass::Upper => "upper",
+            CharClass::Digit => "digit",
+            CharClass::Symbol => "symbol",
+        }
+    }
+
+    pub fn contains(&self, ch: char) -> bool {
+        match self {
+            CharClass::Lower => ch.is_lowercase(),
+            CharClass::Upper => ch.is_uppercase(),
+            CharClass::Digit => ch.is_numeric(),
+            CharClass::Symbol => !ch.is_alphanumeric() && !ch.is_whitespace(),
+        }
+    }
+
+    fn describe(&self) -> &str {
+        match self {
+            CharClass::Lower => "a lowercase letter",
+            CharClass::Upper => "an uppercase letter",
+            CharClass::Digit => "a digit",
+            CharClass::Symbol => "a symbol",
+        }
+    }
+}
+
+// A character of each class. Every missing class is a violation of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharClasses(pub Vec<CharClass>);
+
+impl PasswordPolicy for CharClasses {
+    fn allows(&self, password: &str) -> bool {
+        self.violations(password).is_empty()
+    }
+
+    fn describe(&self) -> String {
+        let classes: Vec<&str> = self.0.iter().map(CharClass::describe).collect();
+        classes.join(", ")
+    }
+
+    fn violations(&self, password: &str) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|class| !password.chars().any(|ch| class.contains(ch)))
+            .map(|class| class.describe().to_string())
+            .collect()
+    }
+}
+
+// None of the words, in any case. Every word found is a violation of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forbidden(pub Vec<String>);
+
+impl PasswordPolicy for Forbidden {
+    fn allows(&self, password: &str) -> bool {
+        self.violations(password).is_empty()
+    }
+
+    fn describe(&self) -> String {
+        let words: Vec<String> = self.0.iter().map(|w| format!("no '{}'", w)).collect();
+        words.join(", ")
+    }
+
+    fn violations(&self, password: &str) -> Vec<String> {
+        let password = password.to_lowercase();
+        self.0
+            .iter()
+            .filter(|word| password.contains(&word.to_lowercase()))
+            .map(|word| format!("no '{}'", word))
+            .collect()
+    }
+}
+
+// The classes of \d, \w and \s, which are Unicode like the character classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shorthand {
+    Digit,
+    Word,
+    Space,
+}
+
+impl Shorthand {
+    fn contains(&self, ch: char) -> bool {
+        match self {
+            Shorthand::Digit => CharClass::Digit.contains(ch),
+            Shorthand::Word => ch.is_alphanumeric() || ch == '_',
+            Shorthand::Space => ch.is_whitespace(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Atom {
+    Any,
+    Char(char),
+    // The shorthands are negated on their own, like \D in [\Dx].
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+        shorthands: Vec<(Shorthand, bool)>,
+    },
+}
+
+impl Atom {
+    fn matches(&self, ch: char) -> bool {
+        match self {
+            Atom::Any => true,
+            Atom::Char(c) => *c == ch,
+            Atom::Class {
+                negated,
+                ranges,
+                shorthands,
+            } => {
+                let in_range = ranges.iter().any(|&(low, high)| low <= ch && ch <= high);
+                let in_shorthand = shorthands.iter().any(|&(s, not)| s.contains(ch) != not);
+                (in_range || in_shorthand) != *negated
+            }
+        }
+    }
+}
+
+// The character after a backslash: \d, \w and \s, their negations \D, \W and \S,
+// or a punctuation character which stands for itself, like \. or \[.
+fn escape(ch: char) -> Result<Atom, String> {
+    let shorthand = match ch.to_ascii_lowercase() {
+        'd' => Shorthand::Digit,
+        'w' => Shorthand::Word,
+        's' => Shorthand::Space,
+        _ if ch.is_ascii_punctuation() => return Ok(Atom::Char(ch)),
+        _ => return Err(format!("unknown escape '\\{}'", ch)),
+    };
+    Ok(Atom::Class {
+        negated: false,
+        ranges: Vec::new(),
+        shorthands: vec![(shorthand, ch.is_ascii_uppercase())],
+    })
+}
+
+// A character or an escape at index i of a class starting at index start.
+fn read_member<I>(
+    chars: &mut Peekable<I>,
+    (i, ch): (usize, char),
+    start: usize,
+) -> Result<Atom, (usize, String)>
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    if ch != '\\' {
+        return Ok(Atom::Char(ch));
+    }
+    let (_, ch) = chars
+        .next()
+        .ok_or_else(|| (start, String::from("unclosed '['")))?;
+    escape(ch).map_err(|err| (i, err))
+}
+
+// The inside of a class like [a-z_], after the '[' at index start. The errors
+// come with the index they point at. A ']' right at the start and a '-' at the
+// end are themselves.
+fn read_class<I>(chars: &mut Peekable<I>, start: usize) -> Result<Atom, (usize, String)>
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    let unclosed = || (start, String::from("unclosed '['"));
+    let negated = chars.next_if(|&(_, ch)| ch == '^').is_some();
+    let mut ranges = Vec::new();
+    let mut shorthands = Vec::new();
+    loop {
+        let (i, ch) = chars.next().ok_or_else(unclosed)?;
+        if ch == ']' && !(ranges.is_empty() && shorthands.is_empty()) {
+            return Ok(Atom::Class {
+                negated,
+                ranges,
+                shorthands,
+            });
+        }
+        let low = match read_member(chars, (i, ch), start)? {
+            Atom::Char(ch) => ch,
+            Atom::Class {
+                shorthands: more, ..
+            } => {
+                shorthands.extend(more);
+                continue;
+            }
+            Atom::Any => unreachable!(),
+        };
+        let high = match chars.peek() {
+            Some(&(_, '-')) => {
+                chars.next();
+                match *chars.peek().ok_or_else(unclosed)? {
+                    (_, ']') => {
+                        ranges.push(('-', '-'));
+                        low
+                    }
+                    next => {
+                        chars.next();
+                        match read_member(chars, next, start)? {
+                            Atom::Char(ch) => ch,
+                            _ => return Err((next.0, String::from("a range ends with a class"))),
+                        }
+                    }
+                }
+            }
+            _ => low,
+        };
+        if high < low {
+            return Err((i, format!("the range {}-{} is inverted", low, high)));
+        }
+        ranges.push((low, high));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Repeat {
+    Once,
+    Optional,
+    Any,
+    Many,
+}
+
+// A small regular expression for password rules: characters, '.', classes like [a-z0-9]
+// or [^ ], the escapes \d, \w and \s and their negations, the repetitions *, + and ?,
+// and the anchors ^ and $. The escapes are Unicode like the character classes.
+// There are no groups or alternatives. Without ^ the pattern may match anywhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    source: String,
+    start: bool,
+    end: bool,
+    items: Vec<(Atom, Repeat)>,
+}
+
+impl Pattern {
+    // The pattern is a part of the line, to point at the errors.
+    pub fn parse(line: &str, pattern: &str) -> Result<Self, ParseError> {
+        let err = |i: usize, message: String| ParseError::at(line, &pattern[i..], message);
+        let err_at = |(i, message): (usize, String)| err(i, message);
+
+        let mut chars = pattern.char_indices().peekable();
+        let start = chars.next_if(|&(_, ch)| ch == '^').is_some();
+        let mut end = false;
+        let mut items: Vec<(Atom, Repeat)> = Vec::new();
+        while let Some((i, ch)) = chars.next() {
+            let atom = match ch {
+                '$' if chars.peek().is_none() => {
+                    end = true;
+                    break;
+                }
+                '.' => Atom::Any,
+                '*' | '+' | '?' => {
+                    let repeat = match ch {
+                        '*' => Repeat::Any,
+                        '+' => Repeat::Many,
+                        _ => Repeat::Optional,
+                    };
+                    match items.last_mut() {
+                        Some((_, last @ Repeat::Once)) => *last = repeat,
+                        _ => return Err(err(i, format!("nothing to repeat with '{}'", ch))),
+                    }
+                    continue;
+                }
+                '\\' => match chars.next() {
+                    Some((_, ch)) => escape(ch).map_err(|message| err(i, message))?,
+                    None => return Err(err(i, String::from("nothing to escape"))),
+                },
+                '[' => read_class(&mut chars, i).map_err(err_at)?,
+                '(' | ')' | '|' | '{' | '}' => {
+                    return Err(err(i, format!("'{}' is not supported", ch)));
+                }
+                _ => Atom::Char(ch),
+            };
+            items.push((atom, Repeat::Once));
+        }
+
+        Ok(Self {
+            source: pattern.to_string(),
+            start,
+            end,
+            items,
+        })
+    }
+
+    // The positions of the items still to match, like a Thompson NFA. Each item is
+    // one state, and a '+' is split into a single atom and an optional loop on it,
+    // so the time is linear in the text and in the pattern.
+    pub fn is_match(&self, s: &str) -> bool {
+        let mut steps: Vec<(&Atom, bool, bool)> = Vec::new();
+        for (atom, repeat) in &self.items {
+            match repeat {
+                Repeat::Once => steps.push((atom, false, false)),
+                Repeat::Optional => steps.push((atom, true, false)),
+                Repeat::Any => steps.push((atom, true, true)),
+                Repeat::Many => steps.extend([(atom, false, false), (atom, true, true)]),
+            }
+        }
+        // The state and every state after it which may be skipped.
+        let enter = |states: &mut Vec<bool>, mut i: usize| {
+            while !states[i] {
+                states[i] = true;
+                match steps.get(i) {
+                    Some(&(_, true, _)) => i += 1,
+                    _ => break,
+                }
+            }
+        };
+        let done = steps.len();
+
+        let mut states = vec![false; done + 1];
+        enter(&mut states, 0);
+        for ch in s.chars() {
+            if states[done] && !self.end {
+                return true;
+            }
+            let mut next = vec![false; done + 1];
+            for (i, &(atom, _, loops)) in steps.iter().enumerate() {
+                if states[i] && atom.matches(ch) {
+                    enter(&mut next, if loops { i } else { i + 1 });
+                }
+            }
+            if !self.start {
+                enter(&mut next, 0);
+            }
+            states = next;
+        }
+        states[done]
+    }
+}
+
+impl PasswordPolicy for Pattern {
+    fn allows(&self, password: &str) -> bool {
+        self.is_match(password)
+    }
+
+    fn describe(&self) -> String {
+        format!("matching /{}/", self.source)
+    }
+}
+
+// Every one of the policies.
+pub struct AllOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+impl PasswordPolicy for AllOf {
+    fn allows(&self, password: &str) -> bool {
+        self.0.iter().all(|policy| policy.allows(password))
+    }
+
+    fn describe(&self) -> String {
+        let rules: Vec<String> = self.0.iter().map(|policy| policy.describe()).collect();
+        rules.join("; ")
+    }
+
+    fn violations(&self, password: &str) -> Vec<String> {
+        self.0
+            .iter()
+            .flat_map(|policy| policy.violations(password))
+            .collect()
+    }
+}
+
+pub const POLICY_NAMES: [&str; 6] = [
+    "count",
+    "positions",
+    "min-length",
+    "classes",
+    "forbid",
+    "regex",
+];
+
+// A policy by its name and arguments:
+// count:1-3 a
+// positions:1-3 a
+// min-length:12
+// classes:lower,upper,digit,symbol
+// forbid:password,qwerty
+// regex:^[a-z]+$
+pub fn policy(spec: &str) -> Result<Box<dyn PasswordPolicy>, ParseError> {
+    let (name, args) = match spec.split_once(':') {
+        Some((name, args)) => (name.trim(), args),
+        None => return Err(ParseError::at(spec, spec, "expected 'name:arguments'")),
+    };
+    match name {
+        "count" => {
+            let (min, max, letter) = read_rule(spec, args.trim())?;
+            Ok(Box::new(LetterCount { letter, min, max }))
+        }
+        "positions" => {
+            let (first, second, letter) = read_rule(spec, args.trim())?;
+            Ok(Box::new(LetterPositions {
+                letter,
+                first,
+                second,
+            }))
+        }
+        "min-length" => Ok(Box::new(MinLength(parse::number(spec, args.trim())?))),
+        "classes" => {
+            let mut classes = Vec::new();
+            for name in args.split(',').map(str::trim) {
+                let class = CharClass::values()
+                    .iter()
+                    .copied()
+                    .find(|class| class.name() == name)
+                    .ok_or_else(|| {
+                        let err = format!(
+                            "unknown class '{}' (expected lower, upper, digit or symbol)",
+                            name
+                        );
+                        ParseError::at(spec, name, err)
+                    })?;
+                classes.push(class);
+            }
+            Ok(Box::new(CharClasses(classes)))
+        }
+        "forbid" => {
+            let mut words = Vec::new();
+            for word in args.split(',').map(str::trim) {
+                if word.is_empty() {
+                    return Err(ParseError::at(spec, word, "empty word"));
+                }
+                words.push(word.to_string());
+            }
+            Ok(Box::new(Forbidden(words)))
+        }
+        "regex" => Ok(Box::new(Pattern::parse(spec, args)?)),
+        _ => {
+            let err = format!(
+                "unknown policy '{}' (expected {})",
+                name,
+                POLICY_NAMES.join(", ")
+            );
+            Err(ParseError::at(spec, name, err))
+        }
+    }
+}
+
+// A password of a dump which misses some requirements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding<'a> {
+    pub line: usize,
+    pub password: &'a str,
+    pub violations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Audit<'a> {
+    pub passwords: usize,
+    pub findings: Vec<Finding<'a>>,
+}
+
+// Checks a dump with one password on each line. Blank lines are skipped.
+pub fn audit<'a>(policy: &dyn PasswordPolicy, dump: &'a str) -> Audit<'a> {
+    let mut audit = Audit::default();
+    for (line, password) in parse::lines(dump) {
+        audit.passwords += 1;
+        let violations = policy.violations(password);
+        if !violations.is_empty() {
+            audit.findings.push(Finding {
+                line,
+                password,
+                violations,
+            });
+        }
+    }
+    audit
+}
+
+// "1-3 a" of a line.
+fn read_rule(line: &str, rule: &str) -> Result<(usize, usize, char), ParseError> {
+    let (range, letter) = parse::pair(rule, " ")
+        .ok_or_else(|| ParseError::at(line, rule, "expected 'range letter'"))?;
+    let (start, end) =
+        parse::pair(range, "-").ok_or_else(|| ParseError::at(line, range, "expected 'min-max'"))?;
+
+    let mut letters = letter.chars();
+    let letter = match (letters.next(), letters.next()) {
+        (Some(letter), None) => letter,
+        _ => return Err(ParseError::at(line, letter, "expected a single letter")),
+    };
+
+    Ok((
+        parse::number(line, start)?,
+        parse::number(line, end)?,
+        letter,
+    ))
+}
+
+// Each line gives the password policy and then the password.
+// 1-3 a: abcde
+// 1-3 b: cdefg
+// 2-9 c: ccccccccc
+pub struct Password {
+    start: usize,
+    end: usize,
+    letter: char,
+    password: String,
+}
+
+impl Password {
+    // The policy as the sled rental place down the street understood it.
+    pub fn count_policy(&self) -> LetterCount {
+        LetterCount {
+            letter: self.letter,
+            min: self.start,
+            max: self.end,
+        }
+    }
+
+    // The policy of the Official Toboggan Corporate Authentication System.
+    pub fn position_policy(&self) -> LetterPositions {
+        LetterPositions {
+            letter: self.letter,
+            first: self.start,
+            second: self.end,
+        }
+    }
+}
+
+impl FromStr for Password {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rule, password) = parse::pair(s, ":")
+            .ok_or_else(|| ParseError::at(s, s, "expected 'policy: password'"))?;
+        let (start, end, letter) = read_rule(s, rule)?;
+
+        Ok(Self {
+            start,
+            end,
+            letter,
+            password: password.to_string(),
+        })
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Password>, ParseError> {
+    let mut buf = Vec::new();
+    for (n, line) in parse::lines(input) {
+        buf.push(line.parse().map_err(|e: ParseError| e.on_line(n))?);
+    }
+    Ok(buf)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Password>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // The password policy indicates the lowest and highest number of times a given letter must
+    // appear for the password to be valid.
+    // How many passwords are valid according to their policies?
+    fn part_1(passwords: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let count = passwords
+            .iter()
+            .filter(|pas| pas.count_policy().allows(&pas.password))
+            .count();
+
+        // Your puzzle answer was 572.
+        Ok(count)
+    }
+
+    // --- Part Two ---
+
+    // Each policy actually describes two positions in the password, where 1 means the first
+    // character, 2 means the second character, and so on.
+    // (Be careful; Toboggan Corporate Policies have no concept of "index zero"!)
+    // Exactly one of these positions must contain the given letter. Other occurrences of the
+    // letter are irrelevant for the purposes of policy enforcement.
+    fn part_2(passwords: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let count = passwords
+            .iter()
+            .filter(|pas| pas.position_policy().allows(&pas.password))
+            .count();
+
+        // Your puzzle answer was 306.
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+    use std::time::{Duration, Instant};
+
+    const EXAMPLE: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(2)));
+        assert_eq!(answers.part_2, Some(Answer::Number(1)));
+    }
+
+    #[test]
+    fn patterns() {
+        let matches =
+            |pattern: &str, s: &str| Pattern::parse(pattern, pattern).unwrap().is_match(s);
+        assert!(matches("abc", "xxabcxx"));
+        assert!(!matches("^abc", "xxabc"));
+        assert!(!matches("abc$", "abcx"));
+        assert!(matches("^a.c$", "a-c"));
+        assert!(matches("^[a-z]+[0-9]*$", "hunter2"));
+        assert!(!matches("^[a-z]+[0-9]*$", "Hunter2"));
+        assert!(matches("^[^ ]*$", "correct-horse"));
+        assert!(!matches("^[^ ]*$", "correct horse"));
+        assert!(matches(r"\d\d", "pa55"));
+        assert!(matches(r"^\w+\.?$", "abc."));
+        assert!(matches("^ab?c*d+$", "acccd"));
+        assert!(!matches("^ab?c*d+$", "abbd"));
+        assert!(matches("^[]a-]+$", "]-a"));
+        assert!(matches(r"^\$\[$", "$["));
+        assert!(matches("^a*a*ab$", "aaab"));
+        assert!(matches("^a+$", "aaa"));
+        assert!(!matches("^a+$", ""));
+        assert!(matches("a*", ""));
+        assert!(matches("b+c", "abbbc"));
+        assert!(matches(r"^[\D]+$", "ab-"));
+        assert!(!matches(r"^[\D]+$", "a5"));
+        assert!(matches(r"^[\W]+$", "-! "));
+        assert!(!matches(r"^[\W]+$", "\u{e9}"));
+        assert!(!matches(r"^[\W]+$", "-_"));
+        assert!(!matches(r"^[\W]+$", "Z"));
+        assert!(matches(r"^[\S]+$", "a\u{e9}9"));
+        assert!(!matches(r"^[\S]+$", "a b"));
+        assert!(matches(r"^[\Sx]+$", "ab"));
+        assert!(!matches(r"^[^\D]+$", "a"));
+        assert!(matches(r"^\d\w\s$", "\u{663}\u{e9}\u{3000}"));
+        assert!(matches(r"^[\d]+$", "\u{663}9"));
+
+        let error = |pattern: &str| Pattern::parse(pattern, pattern).unwrap_err().to_string();
+        assert_eq!(error("ab(c)"), "column 3: '(' is not supported");
+        assert_eq!(error("a|b"), "column 2: '|' is not supported");
+        assert_eq!(error("*a"), "column 1: nothing to repeat with '*'");
+        assert_eq!(error("a+?"), "column 3: nothing to repeat with '?'");
+        assert_eq!(error("[a-z"), "column 1: unclosed '['");
+        assert_eq!(error(r"ab\"), "column 3: nothing to escape");
+        assert_eq!(error(r"a\n"), r"column 2: unknown escape '\n'");
+        assert_eq!(error(r"[a\q]"), r"column 3: unknown escape '\q'");
+        assert_eq!(error("[az-a]"), "column 3: the range z-a is inverted");
+        assert_eq!(error(r"[a-\d]"), "column 4: a range ends with a class");
+    }
+
+    #[test]
+    fn pathological_patterns() {
+        // Stacked repetitions used to backtrack for an exponential time.
+        let source = "^a*a*a*a*a*a*a*a*a*b$";
+        let pattern = Pattern::parse(source, source).unwrap();
+        let text = "a".repeat(5000);
+        let start = Instant::now();
+        assert!(!pattern.is_match(&text));
+        assert!(pattern.is_match(&(text + "b")));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn policies() {
+        let check = |spec: &str, password: &str| policy(spec).unwrap().violations(password);
+        assert!(check("count:1-3 a", "abcde").is_empty());
+        assert_eq!(check("count:1-3 b", "cdefg"), ["'b' 1 to 3 times"]);
+        assert!(check("positions:1-3 a", "abcde").is_empty());
+        assert_eq!(
+            check("positions:2-9 c", "ccccccccc"),
+            ["'c' at exactly one of the positions 2 and 9"]
+        );
+        assert_eq!(check("min-length:8", "hunter2"), ["at least 8 characters"]);
+        assert_eq!(
+            check("classes:lower,upper,digit,symbol", "hunter2"),
+            ["an uppercase letter", "a symbol"]
+        );
+        assert_eq!(check("forbid:hunter, 123", "Hunter2"), ["no 'hunter'"]);
+        assert_eq!(check("regex:^[a-z]+$", "hunter2"), ["matching /^[a-z]+$/"]);
+
+        let error = |spec: &str| policy(spec).err().unwrap().to_string();
+        assert_eq!(error("min-length"), "column 1: expected 'name:arguments'");
+        assert_eq!(
+            error("length:8"),
+            "column 1: unknown policy 'length' (expected count, positions, min-length, classes, forbid, regex)"
+        );
+        assert_eq!(
+            error("classes:lower,digits"),
+            "column 15: unknown class 'digits' (expected lower, upper, digit or symbol)"
+        );
+        assert_eq!(error("forbid:a,,b"), "column 10: empty word");
+        assert_eq!(error("regex:a(b)"), "column 8: '(' is not supported");
+        assert!(policy("count:3 a").is_err());
+    }
+
+    #[test]
+    fn auditing() {
+        let policy = AllOf(vec![
+            policy("min-length:8").unwrap(),
+            policy("classes:digit").unwrap(),
+            policy("forbid:password").unwrap(),
+        ]);
+        let dump = "correct horse 1\nhunter2\n\nPassword123\n";
+
+        let audit = audit(&policy, dump);
+        assert_eq!(audit.passwords, 3);
+        assert_eq!(
+            audit.findings,
+            [
+                Finding {
+                    line: 2,
+                    password: "hunter2",
+                    violations: vec![String::from("at least 8 characters")],
+                },
+                Finding {
+                    line: 4,
+                    password: "Password123",
+                    violations: vec![String::from("no 'password'")],
+                },
+            ]
+        );
+    }
+}
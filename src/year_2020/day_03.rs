@@ -0,0 +1,264 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+// Right and down steps of a route, like right 3 and down 1.
+pub type Slope = (usize, usize);
+
+// The squares a route passes from the top left corner to the bottom of the map.
+// The positions are on the map itself: going past its right edge wraps around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub path: Vec<Pos>,
+    pub trees: usize,
+}
+
+impl Route {
+    fn new(map: &Grid<char>, path: Vec<Pos>) -> Self {
+        let trees = path.iter().filter(|&&pos| map[pos] == '#').count();
+        Self { path, trees }
+    }
+}
+
+// The same pattern repeats to the right many times.
+// None if the slope doesn't go down.
+pub fn slope_route(map: &Grid<char>, (dx, dy): Slope) -> Option<Route> {
+    if dy == 0 {
+        return None;
+    }
+    let path = (0..map.height())
+        .step_by(dy)
+        .enumerate()
+        .map(|(i, y)| (i * dx % map.width(), y))
+        .collect();
+    Some(Route::new(map, path))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The slopes with steps up to the bound, in lowest terms: right 2 and down 2 is
+// right 1 and down 1 skipping every other row, so it isn't a slope of its own.
+pub fn slopes(bound: usize) -> Vec<Slope> {
+    let mut slopes = Vec::new();
+    for dy in 1..=bound {
+        for dx in 0..=bound {
+            if gcd(dx, dy) == 1 {
+                slopes.push((dx, dy));
+            }
+        }
+    }
+    slopes
+}
+
+// The routes of the slopes up to the bound with the fewest and the most trees.
+// Of equal routes, the one of the earliest slope in `slopes` wins.
+pub fn extreme_slopes(map: &Grid<char>, bound: usize) -> Option<((Slope, Route), (Slope, Route))> {
+    let mut routes = slopes(bound)
+        .into_iter()
+        .filter_map(|slope| Some((slope, slope_route(map, slope)?)));
+    let first = routes.next()?;
+    let (mut fewest, mut most) = (first.clone(), first);
+    for (slope, route) in routes {
+        if route.trees < fewest.1.trees {
+            fewest = (slope, route.clone());
+        }
+        if route.trees > most.1.trees {
+            most = (slope, route);
+        }
+    }
+    Some((fewest, most))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    FewestTrees,
+    MostTrees,
+}
+
+// The best route when each step may be any of the moves, e.g. right 1 or right 3 and
+// down 1. Every square only depends on the rows below it, so the best routes are found
+// from the bottom up. None if there are no moves or one of them doesn't go down.
+pub fn steered_route(map: &Grid<char>, moves: &[Slope], goal: Goal) -> Option<Route> {
+    if moves.is_empty() || moves.iter().any(|&(_, dy)| dy == 0) {
+        return None;
+    }
+    let width = map.width();
+    // The trees from each square on and the move to take there.
+    let mut best: Grid<(usize, Slope)> = Grid::filled(width, map.height(), (0, moves[0]));
+    for y in (0..map.height()).rev() {
+        for x in 0..width {
+            let ahead = moves.iter().map(|&(dx, dy)| {
+                let trees = best.get(((x + dx) % width, y + dy)).map_or(0, |&(n, _)| n);
+                (trees, (dx, dy))
+            });
+            let choice = match goal {
+                Goal::FewestTrees => ahead.min_by_key(|&(trees, _)| trees),
+                Goal::MostTrees => ahead.max_by_key(|&(trees, _)| trees),
+            };
+            let (trees, step) = choice.expect("no moves");
+            best[(x, y)] = (trees + usize::from(map[(x, y)] == '#'), step);
+        }
+    }
+
+    let mut path = Vec::new();
+    let mut pos = (0, 0);
+    while pos.1 < map.height() {
+        path.push(pos);
+        let (dx, dy) = best[pos].1;
+        pos = ((pos.0 + dx) % width, pos.1 + dy);
+    }
+    Some(Route::new(map, path))
+}
+
+// The map with the route drawn on it: X where it hits a tree, O where it doesn't.
+pub fn overlay(map: &Grid<char>, route: &Route) -> Grid<char> {
+    let mut overlay = map.clone();
+    for &pos in &route.path {
+        overlay[pos] = if map[pos] == '#' { 'X' } else { 'O' };
+    }
+    overlay
+}
+
+// None if the slope doesn't go down.
+pub fn count_trees(map: &Grid<char>, dx: usize, dy: usize) -> Option<usize> {
+    Some(slope_route(map, (dx, dy))?.trees)
+}
+
+// Each row of the map is made of open squares (.) and trees (#).
+pub fn read_map(input: &str) -> Result<Grid<char>, ParseError> {
+    let lines: Vec<_> = parse::lines(input).collect();
+    Grid::parse(&lines, |ch| matches!(ch, '.' | '#').then_some(ch))
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_map(input)
+    }
+
+    // Starting at the top-left corner of your map and following a slope of right 3 and down 1,
+    // how many trees would you encounter?
+    fn part_1(map: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        // Your puzzle answer was 228.
+        Ok(count_trees(map, 3, 1).ok_or("the slope doesn't go down")?)
+    }
+
+    // --- Part Two ---
+
+    // What do you get if you multiply together the number of trees encountered on each of the
+    // listed slopes? Slopes:
+    // Right 3, down 1. (This is the slope you already checked.)
+    // Right 1, down 1.
+    // Right 5, down 1.
+    // Right 7, down 1.
+    // Right 1, down 2.
+    fn part_2(map: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut prod = 1;
+        for (dx, dy) in [(3, 1), (1, 1), (5, 1), (7, 1), (1, 2)] {
+            prod *= count_trees(map, dx, dy).ok_or("the slope doesn't go down")?;
+        }
+
+        // Your puzzle answer was 6818112000.
+        Ok(prod)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(7)));
+        assert_eq!(answers.part_2, Some(Answer::Number(336)));
+    }
+
+    #[test]
+    fn routes() {
+        let map = read_map(EXAMPLE).unwrap();
+        let route = slope_route(&map, (3, 1)).unwrap();
+        assert_eq!(route.trees, 7);
+        assert_eq!(&route.path[..4], [(0, 0), (3, 1), (6, 2), (9, 3)]);
+        assert_eq!(
+            overlay(&map, &route).to_string(),
+            "\
+O.##.......
+#..O#...#..
+.#....X..#.
+..#.#...#O#
+.X...##..#.
+..#.X#.....
+.#.#.#.O..#
+.#........X
+#.X#...#...
+#...#X....#
+.#..#...X.#
+"
+        );
+
+        assert_eq!(slope_route(&map, (1, 0)), None);
+        assert_eq!(count_trees(&map, 3, 0), None);
+
+        assert_eq!(slopes(2), [(0, 1), (1, 1), (2, 1), (1, 2)]);
+        let (fewest, most) = extreme_slopes(&map, 3).unwrap();
+        assert_eq!((fewest.0, fewest.1.trees), ((1, 3), 0));
+        assert_eq!((most.0, most.1.trees), ((3, 1), 7));
+        assert_eq!(extreme_slopes(&map, 0), None);
+    }
+
+    // The trees of all the routes with any moves, the slow way.
+    fn all_trees(map: &Grid<char>, moves: &[Slope], (x, y): Pos) -> Vec<usize> {
+        if y >= map.height() {
+            return vec![0];
+        }
+        let tree = usize::from(map[(x, y)] == '#');
+        moves
+            .iter()
+            .flat_map(|&(dx, dy)| all_trees(map, moves, ((x + dx) % map.width(), y + dy)))
+            .map(|trees| trees + tree)
+            .collect()
+    }
+
+    #[test]
+    fn steering() {
+        let map = read_map(EXAMPLE).unwrap();
+        let moves = [(1, 1), (3, 1), (2, 2)];
+        let fewest = steered_route(&map, &moves, Goal::FewestTrees).unwrap();
+        let most = steered_route(&map, &moves, Goal::MostTrees).unwrap();
+
+        let all = all_trees(&map, &moves, (0, 0));
+        assert_eq!(fewest.trees, *all.iter().min().unwrap());
+        assert_eq!(most.trees, *all.iter().max().unwrap());
+        assert_eq!(fewest.path[0], (0, 0));
+        assert_eq!(steered_route(&map, &[(1, 0)], Goal::MostTrees), None);
+    }
+}
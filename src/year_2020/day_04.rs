@@ -0,0 +1,563 @@
+use crate::error::ParseError;
+use crate::parse::{self, Entry, Section};
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+pub type Passport = HashMap<String, String>;
+
+// The rules of the passport fields in a small subset of TOML, like the answer manifest.
+// Each field has a table; fields are optional unless they are required, and a field
+// without a type takes any value. The types and their keys are:
+// year (min, max), measurement (units), hex-color, enum (values) and digits (length).
+pub const PASSPORT_SCHEMA: &str = r#"
+# byr (Birth Year) - four digits; at least 1920 and at most 2002.
+[byr]
+required = true
+type = "year"
+min = 1920
+max = 2002
+
+# iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+[iyr]
+required = true
+type = "year"
+min = 2010
+max = 2020
+
+# eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+[eyr]
+required = true
+type = "year"
+min = 2020
+max = 2030
+
+# hgt (Height) - a number followed by either cm or in:
+# If cm, the number must be at least 150 and at most 193.
+# If in, the number must be at least 59 and at most 76.
+[hgt]
+required = true
+type = "measurement"
+units = "cm 150-193, in 59-76"
+
+# hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+[hcl]
+required = true
+type = "hex-color"
+
+# ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+[ecl]
+required = true
+type = "enum"
+values = "amb blu brn gry grn hzl oth"
+
+# pid (Passport ID) - a nine-digit number, including leading zeroes.
+[pid]
+required = true
+type = "digits"
+length = 9
+
+# cid (Country ID) - ignored, missing or not.
+[cid]
+"#;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unit {
+    pub name: String,
+    pub min: u32,
+    pub max: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    Any,
+    Year { min: u32, max: u32 },
+    Measurement(Vec<Unit>),
+    HexColor,
+    Enum(Vec<String>),
+    Digits(usize),
+}
+
+fn is_digits(s: &str, len: usize) -> bool {
+    s.len() == len && s.chars().all(|c| c.is_ascii_digit())
+}
+
+impl FieldType {
+    // The keys of the type besides "required" and "type".
+    fn keys(name: &str) -> Option<&'static [&'static str]> {
+        match name {
+            "year" => Some(&["min", "max"]),
+            "measurement" => Some(&["units"]),
+            "hex-color" => Some(&[]),
+            "enum" => Some(&["values"]),
+            "digits" => Some(&["length"]),
+            _ => None,
+        }
+    }
+
+    // Why the value doesn't fit the type, if it doesn't.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let fits = match self {
+            FieldType::Any => true,
+            FieldType::Year { min, max } => {
+                is_digits(value, 4) && value.parse().is_ok_and(|y: u32| y >= *min && y <= *max)
+            }
+            FieldType::Measurement(units) => return check_measurement(units, value),
+            FieldType::HexColor => value
+                .strip_prefix('#')
+                .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit())),
+            FieldType::Enum(values) => values.iter().any(|v| v == value),
+            FieldType::Digits(len) => is_digits(value, *len),
+        };
+        if fits {
+            Ok(())
+        } else {
+            Err(format!("'{}' is not {}", value, self))
+        }
+    }
+}
+
+fn check_measurement(units: &[Unit], value: &str) -> Result<(), String> {
+    let names: Vec<&str> = units.iter().map(|u| u.name.as_str()).collect();
+    let end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(end);
+    if number.is_empty() {
+        return Err(format!("'{}' has no number", value));
+    }
+    if unit.is_empty() {
+        return Err(format!(
+            "'{}' has no unit, expected {}",
+            value,
+            names.join(" or ")
+        ));
+    }
+    let unit = units.iter().find(|u| u.name == unit).ok_or_else(|| {
+        format!(
+            "'{}' has an unknown unit, expected {}",
+            value,
+            names.join(" or ")
+        )
+    })?;
+    match number.parse::<u32>() {
+        Ok(n) if n >= unit.min && n <= unit.max => Ok(()),
+        _ => Err(format!(
+            "'{}' is not from {} to {} {}",
+            value, unit.min, unit.max, unit.name
+        )),
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            FieldType::Any => write!(f, "anything"),
+            FieldType::Year { min, max } => write!(f, "a year from {} to {}", min, max),
+            FieldType::Measurement(units) => {
+                let units: Vec<String> = units
+                    .iter()
+                    .map(|u| format!("{}-{} {}", u.min, u.max, u.name))
+                    .collect();
+                write!(f, "one of {}", units.join(", "))
+            }
+            FieldType::HexColor => write!(f, "a # and six hex digits"),
+            FieldType::Enum(values) => write!(f, "one of {}", values.join(", ")),
+            FieldType::Digits(len) => write!(f, "{} digits", len),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub kind: FieldType,
+}
+
+// A field of a passport which breaks the schema, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub reason: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+// The quoted text of an entry.
+fn text<'a>(entry: &Entry<'a>) -> Result<&'a str, ParseError> {
+    entry
+        .quoted()
+        .ok_or_else(|| entry.error(entry.value, "expected a quoted text"))
+}
+
+// "cm 150-193, in 59-76"
+fn read_units(entry: &Entry) -> Result<Vec<Unit>, ParseError> {
+    let mut units = Vec::new();
+    for item in text(entry)?.split(',').map(str::trim) {
+        let err = |message: &str| entry.error(item, message);
+        let (name, range) = parse::pair(item, " ").ok_or_else(|| err("expected 'unit min-max'"))?;
+        let (min, max) = parse::pair(range, "-").ok_or_else(|| err("expected 'unit min-max'"))?;
+        units.push(Unit {
+            name: name.to_string(),
+            min: parse::number(entry.line, min).map_err(|e| e.on_line(entry.n))?,
+            max: parse::number(entry.line, max).map_err(|e| e.on_line(entry.n))?,
+        });
+    }
+    Ok(units)
+}
+
+fn read_field(section: &Section) -> Result<Field, ParseError> {
+    let get = |key: &str| section.get(key);
+    let need = |key: &str| {
+        get(key).ok_or_else(|| section.error(format!("field '{}' needs '{}'", section.name, key)))
+    };
+
+    let (kind, keys) = match get("type") {
+        None => (String::from("any"), &[][..]),
+        Some(entry) => {
+            let kind = text(entry)?;
+            let keys = FieldType::keys(kind).ok_or_else(|| {
+                let err = "unknown type, expected year, measurement, hex-color, enum or digits";
+                entry.error(kind, err)
+            })?;
+            (kind.to_string(), keys)
+        }
+    };
+    for entry in &section.entries {
+        if !["required", "type"].contains(&entry.key) && !keys.contains(&entry.key) {
+            let err = format!("unexpected key '{}' for the type {}", entry.key, kind);
+            return Err(entry.error(entry.key, err));
+        }
+    }
+
+    let required = match get("required") {
+        None => false,
+        Some(entry) => match entry.value {
+            "true" => true,
+            "false" => false,
+            _ => return Err(entry.error(entry.value, "expected true or false")),
+        },
+    };
+    let kind = match kind.as_str() {
+        "year" => FieldType::Year {
+            min: need("min")?.number()?,
+            max: need("max")?.number()?,
+        },
+        "measurement" => FieldType::Measurement(read_units(need("units")?)?),
+        "hex-color" => FieldType::HexColor,
+        "enum" => {
+            let values = text(need("values")?)?;
+            FieldType::Enum(values.split_whitespace().map(String::from).collect())
+        }
+        "digits" => FieldType::Digits(need("length")?.number()?),
+        _ => FieldType::Any,
+    };
+
+    Ok(Field {
+        name: section.name.to_string(),
+        required,
+        kind,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let sections = parse::sections(text)?;
+        for (i, section) in sections.iter().enumerate() {
+            if sections[..i].iter().any(|other| other.name == section.name) {
+                return Err(section.error(format!("field '{}' is repeated", section.name)));
+            }
+        }
+
+        let fields = sections.iter().map(read_field).collect::<Result<_, _>>()?;
+        Ok(Self { fields })
+    }
+
+    pub fn read(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text =
+            fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        Self::parse(&text).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+
+    pub fn has_required_fields(&self, passport: &Passport) -> bool {
+        self.fields
+            .iter()
+            .all(|field| !field.required || passport.contains_key(&field.name))
+    }
+
+    // The fields which are missing or have an invalid value, in the order of the schema.
+    // The fields which are not in the schema are not checked.
+    pub fn validate(&self, passport: &Passport) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            let reason = match passport.get(&field.name) {
+                None if field.required => String::from("missing"),
+                None => continue,
+                Some(value) => match field.kind.check(value) {
+                    Ok(()) => continue,
+                    Err(reason) => reason,
+                },
+            };
+            errors.push(FieldError {
+                field: field.name.clone(),
+                reason,
+            });
+        }
+        errors
+    }
+}
+
+// Each passport is represented as a sequence of key:value pairs separated by spaces or newlines.
+// Passports are separated by blank lines.
+fn read_input(input: &str) -> Result<Vec<Passport>, ParseError> {
+    let mut pas = Vec::new();
+    for group in parse::groups(input) {
+        let mut next = Passport::new();
+        for (n, s) in group {
+            for (key, value) in parse::key_values(s).map_err(|e| e.on_line(n))? {
+                next.insert(key.to_string(), value.to_string());
+            }
+        }
+        pas.push(next);
+    }
+
+    Ok(pas)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // Count the number of valid passports - those that have all required fields.
+    fn part_1(items: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let schema = Schema::parse(PASSPORT_SCHEMA)?;
+        let count = items
+            .iter()
+            .filter(|pas| schema.has_required_fields(pas))
+            .count();
+
+        // Your puzzle answer was 239.
+        Ok(count)
+    }
+
+    // --- Part Two ---
+    // Count the number of valid passports - those that have all required fields and valid values.
+    fn part_2(items: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let schema = Schema::parse(PASSPORT_SCHEMA)?;
+        let count = items
+            .iter()
+            .filter(|pas| schema.validate(pas).is_empty())
+            .count();
+
+        // Your puzzle answer was 188.
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+    use std::env;
+
+    const EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+    const INVALID: &str = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+
+    const VALID: &str = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(2)));
+        assert_eq!(answers.part_2, Some(Answer::Number(2)));
+
+        let answers = solve_example::<Puzzle>(INVALID, Part::Two);
+        assert_eq!(answers.part_2, Some(Answer::Number(0)));
+        let answers = solve_example::<Puzzle>(VALID, Part::Two);
+        assert_eq!(answers.part_2, Some(Answer::Number(4)));
+    }
+
+    fn passport(s: &str) -> Passport {
+        read_input(s).unwrap().pop().unwrap()
+    }
+
+    #[test]
+    fn validation() {
+        let schema = Schema::parse(PASSPORT_SCHEMA).unwrap();
+        let reasons = |s: &str| -> Vec<String> {
+            let errors = schema.validate(&passport(s));
+            errors.iter().map(FieldError::to_string).collect()
+        };
+
+        assert!(
+            reasons("byr:2002 iyr:2010 eyr:2030 hgt:60in hcl:#123abc ecl:brn pid:000000001")
+                .is_empty()
+        );
+        assert_eq!(
+            reasons("byr:2003 iyr:201 hgt:190 hcl:#123abz ecl:wat pid:0123456789 cid:x"),
+            [
+                "byr: '2003' is not a year from 1920 to 2002",
+                "iyr: '201' is not a year from 2010 to 2020",
+                "eyr: missing",
+                "hgt: '190' has no unit, expected cm or in",
+                "hcl: '#123abz' is not a # and six hex digits",
+                "ecl: 'wat' is not one of amb, blu, brn, gry, grn, hzl, oth",
+                "pid: '0123456789' is not 9 digits",
+            ]
+        );
+        assert_eq!(
+            reasons("byr:1920 iyr:2020 eyr:2020 hgt:190in hcl:#123abc ecl:amb pid:012345678"),
+            ["hgt: '190in' is not from 59 to 76 in"]
+        );
+        assert_eq!(
+            reasons("byr:1920 iyr:2020 eyr:2020 hgt:cm hcl:#123abc ecl:amb pid:012345678"),
+            ["hgt: 'cm' has no number"]
+        );
+    }
+
+    #[test]
+    fn schemas() {
+        let schema =
+            Schema::parse("[pin]\nrequired = true\ntype = \"digits\"\nlength = 4\n\n[note]\n")
+                .unwrap();
+        assert_eq!(
+            schema.fields,
+            [
+                Field {
+                    name: String::from("pin"),
+                    required: true,
+                    kind: FieldType::Digits(4)
+                },
+                Field {
+                    name: String::from("note"),
+                    required: false,
+                    kind: FieldType::Any
+                }
+            ]
+        );
+
+        let error = |s: &str| Schema::parse(s).unwrap_err().to_string();
+        assert_eq!(
+            error("type = \"year\""),
+            "line 1, column 1: value outside of a section"
+        );
+        assert_eq!(error("[a\n"), "line 1, column 1: unclosed section header");
+        assert_eq!(
+            error("[a]\n[a]\n"),
+            "line 2, column 2: field 'a' is repeated"
+        );
+        assert_eq!(
+            error("[a]\ntype = \"date\""),
+            "line 2, column 9: unknown type, expected year, measurement, hex-color, enum or digits"
+        );
+        assert_eq!(
+            error("[a]\ntype = year"),
+            "line 2, column 8: expected a quoted text"
+        );
+        assert_eq!(
+            error("[a]\ntype = \"year\"\nmin = 1"),
+            "line 1, column 2: field 'a' needs 'max'"
+        );
+        assert_eq!(
+            error("[a]\ntype = \"digits\"\nlength = 4\nmin = 1"),
+            "line 4, column 1: unexpected key 'min' for the type digits"
+        );
+        assert_eq!(
+            error("[a]\nrequired = yes"),
+            "line 2, column 12: expected true or false"
+        );
+        assert_eq!(
+            error("[a]\ntype = \"measurement\"\nunits = \"cm 1-2, in\""),
+            "line 3, column 18: expected 'unit min-max'"
+        );
+    }
+
+    #[test]
+    fn schema_files() {
+        let dir = env::temp_dir().join(format!("aoc-schema-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("passport.toml");
+        fs::write(&path, PASSPORT_SCHEMA).unwrap();
+        let schema = Schema::read(&path).unwrap();
+        assert_eq!(schema, Schema::parse(PASSPORT_SCHEMA).unwrap());
+
+        fs::write(&path, "[pid]\ntype = \"digits\"\n").unwrap();
+        let err = Schema::read(&path).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            format!(
+                "{}: line 1, column 2: field 'pid' needs 'length'",
+                path.display()
+            )
+        );
+
+        let missing = dir.join("missing.toml");
+        let err = Schema::read(&missing).unwrap_err().to_string();
+        assert!(err.starts_with(&format!("{}: ", missing.display())));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,258 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+// The seats of a plane: the first bits of a boarding pass pick the row (F or B),
+// the last bits pick the column (L or R), each one halving the seats left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plane {
+    row_bits: u32,
+    column_bits: u32,
+}
+
+// The plane of the puzzle has 128 rows of 8 seats.
+pub const PLANE: Plane = Plane {
+    row_bits: 7,
+    column_bits: 3,
+};
+
+// The seat maps are drawn up to a million seats.
+pub const MAX_MAP_BITS: u32 = 20;
+
+impl Plane {
+    // None if the seat IDs would not fit into 32 bits.
+    pub fn new(row_bits: u32, column_bits: u32) -> Option<Self> {
+        if row_bits + column_bits <= 32 {
+            Some(Self {
+                row_bits,
+                column_bits,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> usize {
+        1 << self.column_bits
+    }
+
+    pub fn seats(&self) -> usize {
+        self.rows() * self.columns()
+    }
+
+    // Every seat has a unique seat ID: multiply the row by the columns, then add the column.
+    pub fn seat_id(&self, row: usize, column: usize) -> usize {
+        column + self.columns() * row
+    }
+
+    pub fn seat(&self, id: usize) -> (usize, usize) {
+        (id / self.columns(), id % self.columns())
+    }
+
+    pub fn decode(&self, s: &str) -> Option<(usize, usize)> {
+        // A pass is ASCII, so it can be split by bytes.
+        if !s.is_ascii() || s.len() != (self.row_bits + self.column_bits) as usize {
+            return None;
+        }
+        let (rows, columns) = s.split_at(self.row_bits as usize);
+        // The lower half is F or L, the upper half is B or R.
+        let bits = |s: &str, lower, upper| {
+            s.chars().try_fold(0, |n, c| match c {
+                c if c == lower => Some(2 * n),
+                c if c == upper => Some(2 * n + 1),
+                _ => None,
+            })
+        };
+        Some((bits(rows, 'F', 'B')?, bits(columns, 'L', 'R')?))
+    }
+
+    // None if the seat is not on the plane.
+    pub fn encode(&self, row: usize, column: usize) -> Option<String> {
+        if row >= self.rows() || column >= self.columns() {
+            return None;
+        }
+        let bits = |n: usize, count: u32, lower, upper| {
+            (0..count)
+                .rev()
+                .map(move |i| if n >> i & 1 == 0 { lower } else { upper })
+        };
+        let rows = bits(row, self.row_bits, 'F', 'B');
+        Some(
+            rows.chain(bits(column, self.column_bits, 'L', 'R'))
+                .collect(),
+        )
+    }
+
+    // Occupied seats are '#' and free seats '.', except for the missing seats
+    // between two occupied ones, which are 'X'. IDs off the plane are ignored.
+    // None if the plane has more than MAX_MAP_BITS bits of seats.
+    pub fn seat_map(&self, ids: &[usize]) -> Option<Grid<char>> {
+        if self.row_bits + self.column_bits > MAX_MAP_BITS {
+            return None;
+        }
+        let mut occupied = vec![false; self.seats()];
+        for &id in ids {
+            if let Some(seat) = occupied.get_mut(id) {
+                *seat = true;
+            }
+        }
+        let mut map = Grid::filled(self.columns(), self.rows(), '.');
+        for (id, &taken) in occupied.iter().enumerate() {
+            let (row, column) = self.seat(id);
+            let between = id > 0 && occupied[id - 1] && occupied.get(id + 1) == Some(&true);
+            map[(column, row)] = match (taken, between) {
+                (true, _) => '#',
+                (false, true) => 'X',
+                (false, false) => '.',
+            };
+        }
+        Some(map)
+    }
+}
+
+// The seat between the first two sorted IDs which are two apart.
+fn find_seat(ids: &[usize]) -> Option<usize> {
+    ids.windows(2).find(|w| w[0] + 2 == w[1]).map(|w| w[0] + 1)
+}
+
+fn read_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut buf = Vec::new();
+    for (n, line) in parse::lines(input) {
+        let t = line.trim();
+        let (row, col) = match PLANE.decode(t) {
+            Some(seat) => seat,
+            _ => {
+                let err = "expected 7 of F or B and then 3 of L or R";
+                return Err(ParseError::at(line, t, err).on_line(n));
+            }
+        };
+        buf.push(PLANE.seat_id(row, col));
+    }
+
+    Ok(buf)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut ids = read_input(input)?;
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    // What is the highest seat ID on a boarding pass?
+    fn part_1(ids: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        // Your puzzle answer was 955.
+        Ok(*ids.last().ok_or("no boarding passes")?)
+    }
+
+    // --- Part Two ---
+    // the seats with IDs +1 and -1 from yours will be in your list.
+    fn part_2(ids: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        // Your puzzle answer was 569.
+        Ok(find_seat(ids).ok_or("your seat is not found")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop;
+    use crate::puzzle::{solve_example, Answer, Part};
+    use crate::rng::Rng;
+
+    #[test]
+    fn decoding() {
+        let (row, col) = PLANE.decode("FBFBBFFRLR").expect("invalid decoding");
+        // decoding FBFBBFFRLR reveals that it is the seat at row 44, column 5.
+        assert_eq!(row, 44);
+        assert_eq!(col, 5);
+        assert_eq!(PLANE.seat_id(row, col), 357);
+        // Here are some other boarding passes:
+        // BFFFBBFRRR: row 70, column 7, seat ID 567.
+        let (row, col) = PLANE.decode("BFFFBBFRRR").expect("invalid decoding");
+        assert_eq!(row, 70);
+        assert_eq!(col, 7);
+        assert_eq!(PLANE.seat_id(row, col), 567);
+        // FFFBBBFRRR: row 14, column 7, seat ID 119.
+        let (row, col) = PLANE.decode("FFFBBBFRRR").expect("invalid decoding");
+        assert_eq!(row, 14);
+        assert_eq!(col, 7);
+        assert_eq!(PLANE.seat_id(row, col), 119);
+        // BBFFBBFRLL: row 102, column 4, seat ID 820.
+        let (row, col) = PLANE.decode("BBFFBBFRLL").expect("invalid decoding");
+        assert_eq!(row, 102);
+        assert_eq!(col, 4);
+        assert_eq!(PLANE.seat_id(row, col), 820);
+    }
+
+    #[test]
+    fn example() {
+        let passes = "FBFBBFFRLR\nBFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n";
+        let answers = solve_example::<Puzzle>(passes, Part::One);
+        assert_eq!(answers.part_1, Some(Answer::Number(820)));
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(PLANE.encode(44, 5).as_deref(), Some("FBFBBFFRLR"));
+        assert_eq!(PLANE.encode(102, 4).as_deref(), Some("BBFFBBFRLL"));
+        assert_eq!(PLANE.encode(128, 0), None);
+        assert_eq!(PLANE.encode(0, 8), None);
+        assert_eq!(PLANE.decode("FBFBBFFRL"), None);
+        assert_eq!(PLANE.decode("FBFBBFFRLB"), None);
+        assert_eq!(PLANE.decode("FBFBBF€R"), None);
+        assert!(read_input("FBFBBF€R\n").is_err());
+        assert_eq!(Plane::new(30, 3), None);
+
+        let plane = Plane::new(2, 1).unwrap();
+        assert_eq!(plane.encode(2, 1).as_deref(), Some("BFR"));
+        assert_eq!(plane.decode("BFR"), Some((2, 1)));
+        assert_eq!(plane.seat(plane.seat_id(2, 1)), (2, 1));
+    }
+
+    #[test]
+    fn round_trips() {
+        let pass = |rng: &mut Rng| {
+            let plane = Plane::new(rng.below(12) as u32, rng.below(8) as u32).unwrap();
+            let (row, column) = (rng.below(plane.rows()), rng.below(plane.columns()));
+            (plane, row, column)
+        };
+        prop::check("decoding an encoded seat", pass, |&(plane, row, column)| {
+            let encoded = plane.encode(row, column).unwrap();
+            plane.decode(&encoded) == Some((row, column))
+        });
+        prop::check(
+            "seat IDs are on the plane",
+            pass,
+            |&(plane, row, column)| {
+                let id = plane.seat_id(row, column);
+                id < plane.seats() && plane.seat(id) == (row, column)
+            },
+        );
+    }
+
+    #[test]
+    fn seat_maps() {
+        let plane = Plane::new(2, 2).unwrap();
+        let ids = [0, 1, 2, 4, 5, 7, 9, 15, 99];
+        assert_eq!(
+            plane.seat_map(&ids).unwrap().to_string(),
+            "###X\n##X#\nX#..\n...#\n"
+        );
+        assert_eq!(find_seat(&ids), Some(3));
+        assert_eq!(Plane::new(16, 16).unwrap().seat_map(&ids), None);
+        assert!(Plane::new(10, 10).unwrap().seat_map(&ids).is_some());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashSet;
+use std::error::Error;
+
+pub type Group = Vec<String>;
+
+fn read_input(input: &str) -> Result<Vec<Group>, ParseError> {
+    // Each group's answers are separated by a blank line,
+    // and within each group, each person's answers are on a single line.
+
+    let mut groups = Vec::new();
+    for lines in parse::groups(input) {
+        let mut group = Group::new();
+        for (n, s) in lines {
+            let t = s.trim();
+            // The questions are marked with the letters a through z.
+            if let Some((i, _)) = t.char_indices().find(|(_, ch)| !ch.is_ascii_lowercase()) {
+                return Err(ParseError::at(s, &t[i..], "expected a letter from a to z").on_line(n));
+            }
+            group.push(t.to_string());
+        }
+        groups.push(group);
+    }
+
+    Ok(groups)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Group>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // For each group, count the number of questions to which anyone answered "yes".
+    // What is the sum of those counts?
+    fn part_1(groups: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let count: usize = groups
+            .iter()
+            .map(|grp| {
+                let mut answers: HashSet<char> = HashSet::new();
+                for user in grp {
+                    answers.extend(user.chars());
+                }
+                answers.len()
+            })
+            .sum();
+
+        // Your puzzle answer was 6911.
+        Ok(count)
+    }
+
+    // --- Part Two ---
+    // For each group, count the number of questions to which everyone answered "yes".
+    // What is the sum of those counts?
+    fn part_2(groups: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let count: usize = groups
+            .iter()
+            .map(|grp| {
+                if let Some(first) = grp.first() {
+                    let mut answers: HashSet<char> = first.chars().collect();
+
+                    for next in &grp[1..] {
+                        let next_answers: HashSet<char> = next.chars().collect();
+                        answers.retain(|ch| next_answers.contains(ch));
+                    }
+
+                    answers.len()
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        // Your puzzle answer was 3473.
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(11)));
+        assert_eq!(answers.part_2, Some(Answer::Number(6)));
+    }
+}
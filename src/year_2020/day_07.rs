@@ -0,0 +1,224 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+
+pub type Bag = HashMap<String, usize>;
+pub type BagMap = HashMap<String, Bag>;
+
+// None if there is no rule for the color.
+fn count_bags(bag_map: &BagMap, bag_color: &str) -> Option<usize> {
+    let mut total = 1;
+    for (bag, count) in bag_map.get(bag_color)? {
+        total += count_bags(bag_map, bag)? * count;
+    }
+    Some(total)
+}
+
+// A color whose bags end up inside of themselves, searching from the colors in turn.
+fn find_cycle<'a>(bag_map: &'a BagMap, colors: &[&'a str]) -> Option<&'a str> {
+    // The colors on the current path are false, the ones searched through are true.
+    let mut done: HashMap<&str, bool> = HashMap::new();
+    for &start in colors {
+        let mut stack = vec![(start, false)];
+        while let Some((color, leaving)) = stack.pop() {
+            if leaving {
+                done.insert(color, true);
+                continue;
+            }
+            if done.contains_key(color) {
+                continue;
+            }
+            done.insert(color, false);
+            stack.push((color, true));
+            for inner in bag_map[color].keys() {
+                match done.get(inner.as_str()) {
+                    Some(false) => return Some(inner),
+                    Some(true) => (),
+                    None => stack.push((inner, false)),
+                }
+            }
+        }
+    }
+    None
+}
+
+fn read_number(s: &str) -> Option<(usize, &str)> {
+    s.find(|ch: char| !ch.is_ascii_digit())
+        .and_then(|pos| match s[0..pos].parse() {
+            Ok(n) => Some((n, s[pos..].trim())),
+            _ => None,
+        })
+}
+
+// content: <number> <color-name> bag[s], <number> <color-name> bag[s].
+//      or: no other bags.
+fn new_bag(line: &str, mut content: &str) -> Result<Bag, ParseError> {
+    let splitter = "bag";
+    let mut bag = Bag::new();
+    if content == "no other bags." {
+        return Ok(bag);
+    }
+    while !content.is_empty() {
+        let (n, rest) = read_number(content)
+            .ok_or_else(|| ParseError::at(line, content, "expected a number of bags"))?;
+        let (color, mut data) = parse::pair(rest, splitter)
+            .ok_or_else(|| ParseError::at(line, rest, "expected a bag color"))?;
+        bag.insert(color.to_string(), n);
+        // Consume 's' or ',' or '.'
+        while data.starts_with("s") || data.starts_with(",") || data.starts_with(".") {
+            data = &data[1..];
+        }
+        content = data.trim();
+    }
+    Ok(bag)
+}
+
+fn read_input(input: &str) -> Result<BagMap, ParseError> {
+    let mut bags = BagMap::new();
+    let mut contents = Vec::new();
+    let mut colors = Vec::new();
+
+    // Each key: value pairs are separated by 'bags contain'.
+    let splitter = "bags contain";
+    for (n, s) in parse::lines(input) {
+        let (key, val) = parse::pair(s, splitter)
+            .ok_or_else(|| ParseError::at(s, s, "expected '<color> bags contain'").on_line(n))?;
+        let bag = new_bag(s, val).map_err(|e| e.on_line(n))?;
+        contents.extend(bag.keys().map(|color| (n, color.clone())));
+        bags.insert(key.to_string(), bag);
+        colors.push((n, key));
+    }
+
+    // Every bag inside of another one needs its own rule.
+    for (n, color) in contents {
+        if !bags.contains_key(&color) {
+            return Err(ParseError::new(format!("no rule for {} bags", color)).on_line(n));
+        }
+    }
+
+    // A bag can't hold itself, which would take endless bags.
+    let order: Vec<&str> = colors.iter().map(|&(_, color)| color).collect();
+    if let Some(color) = find_cycle(&bags, &order) {
+        let n = colors
+            .iter()
+            .find(|&&(_, other)| other == color)
+            .map_or(0, |&(n, _)| n);
+        let err = format!("{} bags end up inside of themselves", color);
+        return Err(ParseError::new(err).on_line(n));
+    }
+
+    Ok(bags)
+}
+
+// How many bag colors can eventually contain at least one "shiny gold" bag?
+const OUR_COLOR: &str = "shiny gold";
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = BagMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_1(bag_map: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut valid_bags: HashSet<String> = HashSet::new();
+        for (color, bags) in bag_map {
+            if bags.contains_key(OUR_COLOR) {
+                valid_bags.insert(color.clone());
+            }
+        }
+        let mut count = valid_bags.len();
+        loop {
+            for (color, bags) in bag_map {
+                for valid_color in &valid_bags {
+                    if bags.contains_key(valid_color) {
+                        valid_bags.insert(color.clone());
+                        break;
+                    }
+                }
+            }
+            if count == valid_bags.len() {
+                break;
+            }
+            count = valid_bags.len();
+        }
+
+        // Your puzzle answer was 155.
+        Ok(count)
+    }
+
+    // --- Part Two ---
+    // How many individual bags are required inside your single shiny gold bag?
+    fn part_2(bag_map: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let count = count_bags(bag_map, OUR_COLOR).ok_or("no shiny gold bag rule")?;
+
+        // Your puzzle answer was 54803.
+        Ok(count - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    const NESTED: &str = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(4)));
+        assert_eq!(answers.part_2, Some(Answer::Number(32)));
+        let answers = solve_example::<Puzzle>(NESTED, Part::Two);
+        assert_eq!(answers.part_2, Some(Answer::Number(126)));
+    }
+
+    #[test]
+    fn bad_rules() {
+        let error = |s: &str| read_input(s).unwrap_err().to_string();
+        assert_eq!(
+            error("dark red bags contain 1 dark blue bag.\n"),
+            "line 1: no rule for dark blue bags"
+        );
+        assert_eq!(
+            error("shiny gold bags contain 1 dark red bag.\ndark red bags contain 1 dark blue bag.\ndark blue bags contain 2 dark red bags.\n"),
+            "line 2: dark red bags end up inside of themselves"
+        );
+        assert_eq!(
+            error("dark red bags contain 1 dark red bag.\n"),
+            "line 1: dark red bags end up inside of themselves"
+        );
+
+        let bag_map = read_input("dark red bags contain no other bags.\n").unwrap();
+        assert_eq!(Puzzle::part_1(&bag_map).unwrap(), 0);
+        let err = Puzzle::part_2(&bag_map).unwrap_err();
+        assert_eq!(err.to_string(), "no shiny gold bag rule");
+    }
+}
@@ -0,0 +1,179 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashSet;
+use std::error::Error;
+
+#[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Instruction {
+    operation: Operation,
+    argument: i64,
+}
+
+impl Instruction {
+    pub fn from(s: &str) -> Result<Self, ParseError> {
+        // Each key: value pairs are separated by space.
+        let splitter = " ";
+        let (op, ar) = parse::pair(s, splitter)
+            .ok_or_else(|| ParseError::at(s, s, "expected an operation and an argument"))?;
+        let argument = parse::number(s, ar)?;
+        let operation = match op {
+            "acc" => Operation::Acc,
+            "jmp" => Operation::Jmp,
+            "nop" => Operation::Nop,
+            _ => return Err(ParseError::at(s, op, format!("unknown operation '{}'", op))),
+        };
+        Ok(Self {
+            operation,
+            argument,
+        })
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut buf = Vec::new();
+
+    for (n, line) in parse::lines(input) {
+        buf.push(Instruction::from(line).map_err(|e| e.on_line(n))?);
+    }
+
+    Ok(buf)
+}
+
+// The accumulator and the index of the instruction where the program stops.
+fn execute(items: &[Instruction]) -> Result<(i64, i64), Box<dyn Error>> {
+    // The accumulator starts at 0.
+    let mut accum: i64 = 0;
+    let mut index: i64 = 0;
+
+    // Immediately before any instruction is executed a second time, what value is in the accumulator?
+    let mut done: HashSet<i64> = HashSet::new();
+    loop {
+        if index < 0 || items.len() <= index as usize || done.contains(&index) {
+            return Ok((accum, index));
+        }
+        done.insert(index);
+
+        let inst = &items[index as usize];
+        let overflow = |what: &str| format!("the {} overflows at instruction {}", what, index + 1);
+        match inst.operation {
+            // nop stands for No OPeration - it does nothing.
+            // The instruction immediately below it is executed next.
+            Operation::Nop => index += 1,
+            // acc increases or decreases a single global accumulator by the value given in the argument.
+            // After an acc instruction, the instruction immediately below it is executed next.
+            Operation::Acc => {
+                accum = accum
+                    .checked_add(inst.argument)
+                    .ok_or_else(|| overflow("accumulator"))?;
+                index += 1
+            }
+            // jmp jumps to a new instruction relative to itself.
+            // The next instruction to execute is found using the argument as an offset from the jmp instruction.
+            Operation::Jmp => {
+                index = index
+                    .checked_add(inst.argument)
+                    .ok_or_else(|| overflow("jump"))?
+            }
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // Run your copy of the boot code.
+    // Immediately before any instruction is executed a second time, what value is in the accumulator?
+    fn part_1(items: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let (accum, _) = execute(items)?;
+
+        // Your puzzle answer was 1928.
+        Ok(accum)
+    }
+
+    // --- Part Two ---
+
+    // The program is supposed to terminate by attempting to execute an instruction immediately after the last instruction in the file.
+    // By changing exactly one jmp or nop, you can repair the boot code and make it terminate correctly.
+    // What is the value of the accumulator after the program terminates?
+    fn part_2(items: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        for (i, inst) in items.iter().enumerate() {
+            if inst.operation != Operation::Acc {
+                // Exactly one instruction is corrupted.
+                // Fix the program so that it terminates normally by changing exactly one jmp (to nop) or nop (to jmp).
+                let mut fix = items.clone();
+                fix[i] = Instruction {
+                    operation: match inst.operation {
+                        Operation::Jmp => Operation::Nop,
+                        Operation::Nop => Operation::Jmp,
+                        op => op,
+                    },
+                    argument: inst.argument,
+                };
+
+                let (accum, index) = execute(&fix)?;
+                if items.len() == index as usize {
+                    // Your puzzle answer was 1319.
+                    return Ok(accum);
+                }
+            }
+        }
+        Err("the boot code cannot be repaired".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(5)));
+        assert_eq!(answers.part_2, Some(Answer::Number(8)));
+    }
+
+    #[test]
+    fn overflows() {
+        let error = |s: &str| {
+            let items = read_input(s).unwrap();
+            Puzzle::part_1(&items).unwrap_err().to_string()
+        };
+        assert_eq!(
+            error("acc +9223372036854775807\nacc +1\n"),
+            "the accumulator overflows at instruction 2"
+        );
+        assert_eq!(
+            error("nop +0\njmp +9223372036854775807\n"),
+            "the jump overflows at instruction 2"
+        );
+    }
+}
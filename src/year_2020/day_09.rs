@@ -0,0 +1,127 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+fn is_valid(preamble: &[i64], value: i64) -> bool {
+    for (i, a) in preamble.iter().enumerate().skip(1) {
+        for b in preamble[0..i].iter() {
+            // A pair which overflows is no match.
+            if a.checked_add(*b) == Some(value) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn find_invalid_item(items: &[i64], preamble_size: usize) -> Option<i64> {
+    for (i, &value) in items.iter().enumerate().skip(preamble_size) {
+        if !is_valid(&items[i - preamble_size..i], value) {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn find_weakness(items: &[i64], invalid_number: i64) -> Option<i64> {
+    for start in 0..items.len() {
+        let mut sum = items[start];
+        for next in start + 1..items.len() {
+            // The sum only grows past the invalid number from here on.
+            sum = match sum.checked_add(items[next]) {
+                Some(sum) => sum,
+                None => break,
+            };
+            if sum == invalid_number {
+                // To find the encryption weakness, add together the smallest and largest number in this contiguous range.
+                let smallest = items[start..=next].iter().min()?;
+                let largest = items[start..=next].iter().max()?;
+                return smallest.checked_add(*largest);
+            } else if sum > invalid_number {
+                break;
+            }
+        }
+    }
+    None
+}
+
+// Find the first number in the list (after the preamble) which is not the sum of two of the 25 numbers before it.
+// The examples have a shorter preamble, so it is a parameter of the solver.
+pub struct Xmas<const PREAMBLE_SIZE: usize>;
+
+pub type Puzzle = Xmas<25>;
+
+impl<const PREAMBLE_SIZE: usize> Solver for Xmas<PREAMBLE_SIZE> {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::numbers(input)
+    }
+
+    // What is the first number that does not have this property?
+    fn part_1(items: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let invalid_number = find_invalid_item(items, PREAMBLE_SIZE).ok_or("invalid items")?;
+
+        // Your puzzle answer was 21806024.
+        Ok(invalid_number)
+    }
+
+    // --- Part Two ---
+    // Find a contiguous set of at least two numbers in your list which sum to the invalid number.
+    fn part_2(items: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let invalid_number = find_invalid_item(items, PREAMBLE_SIZE).ok_or("invalid items")?;
+        let weakness =
+            find_weakness(items, invalid_number).ok_or("the encryption weakness is not found")?;
+
+        // Your puzzle answer was 2986195.
+        Ok(weakness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+";
+
+    #[test]
+    fn example() {
+        // The example has a preamble of 5 numbers.
+        let answers = solve_example::<Xmas<5>>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(127)));
+        assert_eq!(answers.part_2, Some(Answer::Number(62)));
+    }
+
+    #[test]
+    fn overflows() {
+        let items = [1, i64::MAX, i64::MAX, 2, 3];
+        assert_eq!(find_invalid_item(&items, 2), Some(i64::MAX));
+        assert_eq!(find_weakness(&items, 5), Some(5));
+        assert_eq!(find_weakness(&items, i64::MAX), None);
+    }
+}
@@ -1,20 +1,8 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
 use std::collections::HashSet;
-use std::fs;
-use std::io::{BufRead, BufReader};
-
-fn read_input() -> std::io::Result<Vec<i64>> {
-    let file = fs::File::open("./data/day-10.txt")?;
-
-    let mut buf = Vec::new();
-
-    for line in BufReader::new(file).lines() {
-        if let Some(n) = line.ok().and_then(|s| s.trim().parse().ok()) {
-            buf.push(n);
-        }
-    }
-
-    Ok(buf)
-}
+use std::error::Error;
 
 fn get_children(adapters: &[i64], family: &mut HashSet<Vec<i64>>) {
     for i in 2..adapters.len() {
@@ -27,7 +15,7 @@ fn get_children(adapters: &[i64], family: &mut HashSet<Vec<i64>>) {
             if !family.contains(&next) {
                 // Note: all children are shorter than the parent so we can postpone the parent insertion.
                 get_children(&next, family);
-                assert!(!family.contains(&next));
+                debug_assert!(!family.contains(&next));
                 family.insert(next);
             }
         }
@@ -54,52 +42,69 @@ fn group_adapters(adapters: &[i64]) -> Vec<&[i64]> {
     groups
 }
 
-pub fn run() {
-    println!("--- Day 10: Adapter Array ---");
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<i64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let mut adapters = read_input().expect("invalid input");
-    // The charging outlet has an effective rating of 0 jolts.
-    adapters.push(0);
-    adapters.sort();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut adapters = parse::numbers(input)?;
+        // The charging outlet has an effective rating of 0 jolts.
+        adapters.push(0);
+        adapters.sort_unstable();
 
-    // Your device's built-in adapter is always 3 higher than the highest adapter.
-    adapters.push(adapters.last().expect("empty adpter list") + 3);
+        // Your device's built-in adapter is always 3 higher than the highest adapter.
+        adapters.push(
+            adapters
+                .last()
+                .ok_or_else(|| ParseError::new("empty adapter list"))?
+                + 3,
+        );
+
+        Ok(adapters)
+    }
 
     // Find a chain that uses all of your adapters to connect the charging outlet to your device's built-in adapter
     // and count the joltage differences between the charging outlet, the adapters, and your device.
     // What is the number of 1-jolt differences multiplied by the number of 3-jolt differences?
-    let mut diff_1 = 0;
-    let mut diff_3 = 0;
-    for i in 1..adapters.len() {
-        let diff = adapters[i] - adapters[i - 1];
-        // Adapters can only connect to a source 1-3 jolts lower than its rating.
-        if diff == 1 {
-            diff_1 += 1;
-        } else if diff == 3 {
-            diff_3 += 1;
+    fn part_1(adapters: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut diff_1 = 0;
+        let mut diff_3 = 0;
+        for i in 1..adapters.len() {
+            let diff = adapters[i] - adapters[i - 1];
+            // Adapters can only connect to a source 1-3 jolts lower than its rating.
+            if diff == 1 {
+                diff_1 += 1;
+            } else if diff == 3 {
+                diff_3 += 1;
+            }
         }
-    }
 
-    // Your puzzle answer was 2590.
-    println!(
-        "Part 1. The number of 1-jolt differences multiplied by the number of 3-jolt differences: {}",
-        diff_1 * diff_3
-    );
+        // Your puzzle answer was 2590.
+        Ok(diff_1 * diff_3)
+    }
 
     // --- Part Two ---
     // What is the total number of distinct ways you can arrange the adapters to connect the charging outlet to your device?
-    let mut count = 1;
-    for group in group_adapters(&adapters) {
-        count *= count_arrangements(group);
-    }
+    fn part_2(adapters: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut count = 1;
+        for group in group_adapters(adapters) {
+            count = usize::checked_mul(count, count_arrangements(group))
+                .ok_or("the number of arrangements overflows")?;
+        }
 
-    // Your puzzle answer was 226775649501184.
-    println!("Part 2. The number of adapter arangments: {}", count);
+        // Your puzzle answer was 226775649501184.
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
     #[test]
     fn arrangements() {
         let data: &[i64] = &[
@@ -126,4 +131,17 @@ mod tests {
         assert_eq!(count_arrangements(&data[42..46]), 2);
         assert_eq!(count_arrangements(&data[26..46]), 64);
     }
+
+    #[test]
+    fn examples() {
+        let adapters = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n";
+        let answers = solve_example::<Puzzle>(adapters, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(35)));
+        assert_eq!(answers.part_2, Some(Answer::Number(8)));
+
+        let adapters = "28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3\n";
+        let answers = solve_example::<Puzzle>(adapters, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(220)));
+        assert_eq!(answers.part_2, Some(Answer::Number(19208)));
+    }
 }
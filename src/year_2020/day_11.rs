@@ -0,0 +1,153 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos, DIRECTIONS};
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+use std::fmt;
+
+// The seat layout fits neatly on a grid.
+// Each position is either floor (.), an empty seat (L), or an occupied seat (#).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let ch = match self {
+            Seat::Floor => '.',
+            Seat::Empty => 'L',
+            Seat::Occupied => '#',
+        };
+        write!(f, "{}", ch)
+    }
+}
+
+fn read_input(input: &str) -> Result<Grid<Seat>, ParseError> {
+    let lines: Vec<_> = parse::lines(input).collect();
+    Grid::parse(&lines, |ch| match ch {
+        '.' => Some(Seat::Floor),
+        'L' => Some(Seat::Empty),
+        '#' => Some(Seat::Occupied),
+        _ => None,
+    })
+}
+
+// The seats of the eight positions immediately up, down, left, right, or diagonal.
+fn adjacent(seats: &Grid<Seat>, pos: Pos) -> Vec<Pos> {
+    seats
+        .neighbours_8(pos)
+        .filter(|&p| seats[p] != Seat::Floor)
+        .collect()
+}
+
+// The first seat which can be seen in each of those eight directions.
+fn visible(seats: &Grid<Seat>, pos: Pos) -> Vec<Pos> {
+    DIRECTIONS
+        .iter()
+        .filter_map(|&dir| seats.ray(pos, dir).find(|&p| seats[p] != Seat::Floor))
+        .collect()
+}
+
+// If a seat is empty (L) and there are no occupied seats around it, the seat becomes occupied.
+// If a seat is occupied (#) and too many seats around it are also occupied, the seat becomes empty.
+fn next_seats(seats: &Grid<Seat>, around: &Grid<Vec<Pos>>, tolerance: usize) -> Grid<Seat> {
+    seats.map_cells(|pos, &seat| {
+        let mut occupied = around[pos].iter().filter(|&&p| seats[p] == Seat::Occupied);
+        match seat {
+            Seat::Empty if occupied.next().is_none() => Seat::Occupied,
+            Seat::Occupied if occupied.count() >= tolerance => Seat::Empty,
+            _ => seat,
+        }
+    })
+}
+
+// Simulate your seating area by applying the seating rules repeatedly until no seats change state.
+// The floor never changes, so the seats around each seat are looked up once.
+fn settle_down<F>(seats: &Grid<Seat>, look: F, tolerance: usize) -> usize
+where
+    F: Fn(&Grid<Seat>, Pos) -> Vec<Pos>,
+{
+    let around = seats.map_cells(|pos, _| look(seats, pos));
+    let mut seats = seats.clone();
+    loop {
+        let next = next_seats(&seats, &around, tolerance);
+        if next == seats {
+            return seats.iter().filter(|&&s| s == Seat::Occupied).count();
+        }
+        seats = next;
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Grid<Seat>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // How many seats end up occupied?
+    fn part_1(seats: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let count = settle_down(seats, adjacent, 4);
+
+        // Your puzzle answer was 2249.
+        Ok(count)
+    }
+
+    // --- Part Two ---
+
+    // It now takes five or more visible occupied seats for an occupied seat to become empty.
+    fn part_2(seats: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let count = settle_down(seats, visible, 5);
+
+        // Your puzzle answer was 2023.
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(37)));
+        assert_eq!(answers.part_2, Some(Answer::Number(26)));
+    }
+
+    #[test]
+    fn first_round() {
+        let seats = read_input(EXAMPLE).unwrap();
+        assert_eq!(seats.to_string(), EXAMPLE);
+
+        // All the seats are empty, so everyone sits down.
+        let around = seats.map_cells(|pos, _| adjacent(&seats, pos));
+        let next = next_seats(&seats, &around, 4);
+        assert_eq!(next.to_string(), EXAMPLE.replace('L', "#"));
+
+        // From the corner, the floor next to it hides no seat but the one behind it.
+        assert_eq!(adjacent(&next, (0, 0)), [(1, 1), (0, 1)]);
+        assert_eq!(visible(&next, (0, 0)), [(2, 0), (1, 1), (0, 1)]);
+    }
+}
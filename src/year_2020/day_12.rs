@@ -1,11 +1,12 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
 use std::error::Error;
 use std::fmt;
-use std::fs;
-use std::io::{BufRead, BufReader};
 use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug)]
-enum Action {
+pub enum Action {
     North,
     South,
     East,
@@ -17,7 +18,7 @@ enum Action {
 
 impl Action {
     pub fn from_rotation(degrees: i64) -> Self {
-        match (degrees % 360 + 360) % 360 {
+        match degrees.rem_euclid(360) {
             90 => Action::South,
             180 => Action::West,
             270 => Action::North,
@@ -26,7 +27,7 @@ impl Action {
     }
 }
 
-struct Command {
+pub struct Command {
     action: Action,
     amount: i64,
 }
@@ -84,21 +85,20 @@ impl FromStr for Command {
     }
 }
 
-fn read_input() -> Result<Vec<Command>, Box<dyn Error>> {
-    let file = fs::File::open("./data/day-12.txt")?;
-
+fn read_input(input: &str) -> Result<Vec<Command>, ParseError> {
     let mut buf = Vec::new();
-    for line in BufReader::new(file).lines() {
-        let s = line?;
+    for (n, s) in parse::lines(input) {
         let row = s.trim();
-        if !row.is_empty() {
-            buf.push(row.parse()?);
-        }
+        let cmd = row
+            .parse()
+            .map_err(|e: CommandParseError| ParseError::at(s, row, e.message).on_line(n))?;
+        buf.push(cmd);
     }
 
     Ok(buf)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Point {
     x: i64,
     y: i64,
@@ -226,35 +226,98 @@ impl ShipWithWaypoint {
     }
 }
 
-pub fn run() {
-    println!("--- Day 12: Rain Risk ---");
+pub struct Puzzle;
 
-    let input = read_input().expect("invalid input");
+impl Solver for Puzzle {
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
 
     // Figure out where the navigation instructions lead.
     // What is the Manhattan distance between that location and the ship's starting position?
-    let mut ship_v1 = Ship::new();
-    let mut ship_v2 = ShipWithWaypoint::new();
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut ship = Ship::new();
+        for cmd in input {
+            ship.next_move(cmd.action, cmd.amount);
+        }
 
-    for cmd in &input {
-        ship_v1.next_move(cmd.action, cmd.amount);
-        ship_v2.next_move(cmd.action, cmd.amount);
+        // Your puzzle answer was 998.
+        Ok(ship.pos.manhattan_distance())
     }
 
-    // Your puzzle answer was 998.
-    println!(
-        "Part 1. The ship's Manhattan distance: {}",
-        ship_v1.pos.manhattan_distance()
-    );
-
     // --- Part Two ---
 
     // Figure out where the navigation instructions actually lead.
     // What is the Manhattan distance between that location and the ship's starting position?
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut ship = ShipWithWaypoint::new();
+        for cmd in input {
+            ship.next_move(cmd.action, cmd.amount);
+        }
+
+        // Your puzzle answer was 71586.
+        Ok(ship.ship.pos.manhattan_distance())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop;
+    use crate::puzzle::{solve_example, Answer, Part};
+    use crate::rng::Rng;
+
+    fn point(rng: &mut Rng) -> Point {
+        Point {
+            x: rng.range(-1000..=1000),
+            y: rng.range(-1000..=1000),
+        }
+    }
 
-    // Your puzzle answer was 71586.
-    println!(
-        "Part 2. The ship's real Manhattan distance: {}",
-        ship_v2.ship.pos.manhattan_distance()
-    );
+    #[test]
+    fn turn_properties() {
+        prop::check("four turns are no turn", point, |p| {
+            p.turn_right().turn_right().turn_right().turn_right() == *p
+                && p.turn_left().turn_left().turn_left().turn_left() == *p
+        });
+        prop::check("left undoes right", point, |p| {
+            p.turn_left().turn_right() == *p && p.turn_right().turn_left() == *p
+        });
+        prop::check("two turns are a turn back", point, |p| {
+            p.turn_right().turn_right() == p.turn_back()
+                && p.turn_left().turn_left() == p.turn_back()
+                && p.turn_back().turn_back() == *p
+        });
+        prop::check("turns keep the distance", point, |p| {
+            let d = p.manhattan_distance();
+            p.turn_left().manhattan_distance() == d && p.turn_back().manhattan_distance() == d
+        });
+        prop::check(
+            "turns are linear",
+            |rng| (point(rng), point(rng), rng.range(-100..=100)),
+            |(p, q, k)| {
+                p.add(q).turn_right() == p.turn_right().add(&q.turn_right())
+                    && p.mul(*k).turn_left() == p.turn_left().mul(*k)
+            },
+        );
+    }
+
+    const EXAMPLE: &str = "\
+F10
+N3
+F7
+R90
+F11
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(25)));
+        assert_eq!(answers.part_2, Some(Answer::Number(286)));
+    }
 }
@@ -0,0 +1,161 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+fn bus_schedules(s: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let mut schedules = Vec::new();
+
+    for (delay, bus) in s.split(',').enumerate() {
+        let bus = bus.trim();
+        // Entries that show x must be out of service.
+        if bus != "x" {
+            let bus_id: usize = parse::number(s, bus)?;
+            if bus_id == 0 {
+                return Err(ParseError::at(s, bus, "bus IDs start from 1"));
+            }
+            schedules.push((bus_id, delay));
+        }
+    }
+    Ok(schedules)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The inverse of n modulo m, which have to be coprime, by the extended Euclidean algorithm.
+fn inverse(n: usize, m: usize) -> usize {
+    let (mut a, mut b) = ((n % m) as i128, m as i128);
+    // a and b are x * n and y * n modulo m.
+    let (mut x, mut y) = (1, 0);
+    while b != 0 {
+        let q = a / b;
+        (a, b) = (b, a - q * b);
+        (x, y) = (y, x - q * y);
+    }
+    x.rem_euclid(m as i128) as usize
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = (usize, Vec<(usize, usize)>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // The first line is your earliest departure timestamp;
+    // the second one lists the bus IDs in service, 'x' stands for out of service.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input);
+        let (n, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new("missing timestamp"))?;
+        let stamp = parse::number(line, line.trim()).map_err(|e| e.on_line(n))?;
+        let (n, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new("missing bus schedule"))?;
+        let outgo = bus_schedules(line).map_err(|e| e.on_line(n))?;
+
+        Ok((stamp, outgo))
+    }
+
+    // What is the ID of the earliest bus you can take to the airport
+    // multiplied by the number of minutes you'll need to wait for that bus?
+    fn part_1((stamp, sched): &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let (index, time) = sched
+            .iter()
+            .map(|(id, _)| (id - stamp % id) % id)
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .ok_or("no buses in service")?;
+
+        // Your puzzle answer was 3997.
+        Ok(sched[index].0 * time)
+    }
+
+    // --- Part Two ---
+
+    // What is the earliest timestamp such that all of the listed bus IDs depart at offsets matching their positions in the list?
+    fn part_2((_, sched): &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        // Each step needs the inverse of the product of the IDs so far modulo
+        // the next ID, which only exists if they have no common factor.
+        for (i, (a, _)) in sched.iter().enumerate() {
+            if let Some((b, _)) = sched[..i].iter().find(|(b, _)| gcd(*a, *b) != 1) {
+                return Err(format!("the bus IDs {} and {} are not coprime", b, a).into());
+            }
+        }
+
+        if sched.is_empty() {
+            return Err("no buses in service".into());
+        }
+        // The timestamp so far is the smallest one for the buses so far,
+        // so it is below the product of their IDs.
+        let (mut delta, mut next): (usize, usize) = (1, 0);
+        for &(a, delay) in sched {
+            let product = delta.checked_mul(a).ok_or("the timestamp is too large")?;
+            // The bus departs delay minutes after the timestamp. Solve
+            // next + k * delta = target (mod a) for the k below a.
+            let target = (a - delay % a) % a;
+            let diff = (target + a - next % a) % a;
+            let k = (diff as u128 * inverse(delta % a, a) as u128 % a as u128) as usize;
+            // Below delta * a, so it doesn't overflow.
+            next += k * delta;
+            delta = product;
+        }
+
+        // Your puzzle answer was 500033211739354.
+        Ok(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+939
+7,13,x,x,59,x,31,19
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(295)));
+        assert_eq!(answers.part_2, Some(Answer::Number(1068781)));
+
+        for (buses, timestamp) in [
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+        ] {
+            let input = format!("939\n{}\n", buses);
+            let answers = solve_example::<Puzzle>(&input, Part::Two);
+            assert_eq!(answers.part_2, Some(Answer::Number(timestamp)));
+        }
+    }
+
+    #[test]
+    fn odd_schedules() {
+        let part_2 = |buses: &str| {
+            let input = Puzzle::parse(&format!("939\n{}\n", buses)).unwrap();
+            Puzzle::part_2(&input).map_err(|e| e.to_string())
+        };
+        assert_eq!(part_2("7,x,2"), Ok(0));
+        assert_eq!(part_2("7,x,3"), Ok(7));
+        assert_eq!(part_2("x,3"), Ok(2));
+        assert_eq!(part_2("3,x,x,x"), Ok(0));
+        assert_eq!(
+            part_2("4,6"),
+            Err(String::from("the bus IDs 4 and 6 are not coprime"))
+        );
+        assert_eq!(part_2("x,x"), Err(String::from("no buses in service")));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+
+#[derive(Debug)]
+pub struct Task {
+    mask: String,
+    data: Vec<(usize, usize)>,
+}
+
+impl Task {
+    pub fn new(mask: &str) -> Self {
+        Self {
+            mask: mask.to_string(),
+            data: Vec::new(),
+        }
+    }
+}
+
+// The bitmask is always given as a string of 36 bits.
+const MASK_SIZE: usize = 36;
+
+fn read_line(s: &str, buf: &mut Vec<Task>) -> Result<(), ParseError> {
+    let pair = parse::pair(s, "=").ok_or_else(|| ParseError::at(s, s, "expected 'key = value'"))?;
+    if pair.0 == "mask" {
+        if pair.1.len() != MASK_SIZE || !pair.1.chars().all(|ch| "X01".contains(ch)) {
+            let err = format!("expected {} of X, 0 or 1", MASK_SIZE);
+            return Err(ParseError::at(s, pair.1, err));
+        }
+        buf.push(Task::new(pair.1));
+    } else if let Some(adr_part) = pair
+        .0
+        .strip_prefix("mem[")
+        .and_then(|a| a.strip_suffix(']'))
+    {
+        let adr: usize = parse::number(s, adr_part)?;
+        if adr >> MASK_SIZE != 0 {
+            return Err(ParseError::at(s, adr_part, "the address exceeds 36 bits"));
+        }
+        let val: usize = parse::number(s, pair.1)?;
+        if val >> MASK_SIZE != 0 {
+            return Err(ParseError::at(s, pair.1, "the value exceeds 36 bits"));
+        }
+
+        let task = buf
+            .last_mut()
+            .ok_or_else(|| ParseError::at(s, s, "no mask has been set yet"))?;
+        task.data.push((adr, val));
+    } else {
+        return Err(ParseError::at(
+            s,
+            pair.0,
+            "expected 'mask' or 'mem[<address>]'",
+        ));
+    }
+    Ok(())
+}
+
+fn read_input(input: &str) -> Result<Vec<Task>, ParseError> {
+    let mut buf = Vec::new();
+    for (n, s) in parse::lines(input) {
+        read_line(s, &mut buf).map_err(|e| e.on_line(n))?;
+    }
+
+    Ok(buf)
+}
+
+fn decode(mask: &str, addr: usize) -> Vec<usize> {
+    let mut arr = vec![addr];
+    for (i, m) in mask.chars().rev().enumerate() {
+        // If the bitmask bit is 0, the corresponding memory address bit is unchanged.
+        if m == 'X' || m == '1' {
+            let one = 1 << i;
+            let len = arr.len();
+
+            for i in 0..len {
+                let a = arr[i];
+                // If the bitmask bit is 1, the corresponding memory address bit is overwritten with 1.
+                arr[i] = a | one;
+
+                // If the bitmask bit is X, the corresponding memory address bit is floating.
+                if m == 'X' {
+                    arr.push(a & !one);
+                }
+            }
+        }
+    }
+    arr
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Task>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // Execute the initialization program.
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut memo: HashMap<usize, usize> = HashMap::new();
+        for task in input {
+            for line in &task.data {
+                let mut val = *memo.get(&line.0).unwrap_or(&0);
+                for (i, m) in task.mask.chars().rev().enumerate() {
+                    let one = 1 << i;
+                    match m {
+                        'X' => {
+                            val &= !one;
+                            val |= line.1 & one;
+                        }
+                        '0' => val &= !one,
+                        '1' => val |= one,
+                        _ => (),
+                    }
+                }
+                memo.insert(line.0, val);
+            }
+        }
+
+        // What is the sum of all values left in memory after it completes?
+        let n: usize = memo.values().sum();
+
+        // Your puzzle answer was 15172047086292.
+        Ok(n)
+    }
+
+    // --- Part Two ---
+
+    // Execute the initialization program using an emulator for a version 2 decoder chip.
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut memo: HashMap<usize, usize> = HashMap::new();
+        for task in input {
+            for line in &task.data {
+                // A version 2 decoder chip doesn't modify the values being written at all.
+                // Instead, it acts as a memory address decoder.
+                for addr in decode(&task.mask, line.0) {
+                    memo.insert(addr, line.1);
+                }
+            }
+        }
+
+        let n: usize = memo.values().sum();
+
+        // Your puzzle answer was 4197941339968.
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0
+";
+
+    const FLOATING: &str = "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1
+";
+
+    #[test]
+    fn example() {
+        // The first example has too many floating bits for the second part.
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::One);
+        assert_eq!(answers.part_1, Some(Answer::Number(165)));
+        let answers = solve_example::<Puzzle>(FLOATING, Part::Two);
+        assert_eq!(answers.part_2, Some(Answer::Number(208)));
+    }
+
+    #[test]
+    fn bits() {
+        let error = |s: &str| read_input(s).unwrap_err().to_string();
+        let mask = "mask = 000000000000000000000000000000X1001X\n";
+        assert_eq!(
+            error(&format!("{}mem[68719476736] = 1\n", mask)),
+            "line 2, column 5: the address exceeds 36 bits"
+        );
+        assert_eq!(
+            error(&format!("{}mem[68719476735] = 68719476736\n", mask)),
+            "line 2, column 20: the value exceeds 36 bits"
+        );
+    }
+}
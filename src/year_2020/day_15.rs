@@ -0,0 +1,93 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+
+fn nth_spoken(numbers: &[usize], turns: usize) -> usize {
+    // Each turn results in that player speaking aloud either 0 (if the last number is new)
+    // or an age (if the last number is a repeat)
+    let mut number_map: HashMap<usize, usize> = HashMap::new();
+
+    for (val, &key) in numbers.iter().enumerate() {
+        number_map.insert(key, val);
+    }
+
+    let mut last = 0;
+    for i in numbers.len()..turns - 1 {
+        let next = i - number_map.get(&last).unwrap_or(&i);
+        number_map.insert(last, i);
+        last = next;
+    }
+    last
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // The starting numbers are separated by commas.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (n, line) = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::new("no starting numbers"))?;
+        let numbers = parse::number_list(line, line.trim(), ',').map_err(|e| e.on_line(n))?;
+
+        Ok(numbers)
+    }
+
+    // What will be the 2020th number spoken?
+    fn part_1(numbers: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        // Your puzzle answer was 257.
+        Ok(nth_spoken(numbers, 2020))
+    }
+
+    // Determine the 30000000th number spoken.
+    fn part_2(numbers: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        // Your puzzle answer was 8546398.
+        Ok(nth_spoken(numbers, 30_000_000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    #[test]
+    fn example() {
+        for (numbers, spoken) in [
+            ("0,3,6", 436),
+            ("1,3,2", 1),
+            ("2,1,3", 10),
+            ("1,2,3", 27),
+            ("2,3,1", 78),
+            ("3,2,1", 438),
+            ("3,1,2", 1836),
+        ] {
+            let answers = solve_example::<Puzzle>(numbers, Part::One);
+            assert_eq!(answers.part_1, Some(Answer::Number(spoken)));
+        }
+    }
+
+    // Tens of millions of turns take long in a debug build.
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        for (numbers, spoken) in [
+            ("0,3,6", 175594),
+            ("1,3,2", 2578),
+            ("2,1,3", 3544142),
+            ("1,2,3", 261214),
+            ("2,3,1", 6895259),
+            ("3,2,1", 18),
+            ("3,1,2", 362),
+        ] {
+            let answers = solve_example::<Puzzle>(numbers, Part::Two);
+            assert_eq!(answers.part_2, Some(Answer::Number(spoken)));
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub struct Rule {
+    name: String,
+    range_1: RangeInclusive<usize>,
+    range_2: RangeInclusive<usize>,
+}
+
+fn parse_range_inclusive<Idx>(line: &str, s: &str) -> Result<RangeInclusive<Idx>, ParseError>
+where
+    Idx: FromStr,
+    Idx::Err: fmt::Display,
+{
+    let pair = parse::pair(s, "-").ok_or_else(|| ParseError::at(line, s, "expected 'min-max'"))?;
+    let start = parse::number(line, pair.0)?;
+    let end = parse::number(line, pair.1)?;
+
+    Ok(start..=end)
+}
+
+fn read_rule(s: &str) -> Result<Rule, ParseError> {
+    let pair =
+        parse::pair(s, ":").ok_or_else(|| ParseError::at(s, s, "expected 'field: ranges'"))?;
+    let ranges = parse::pair(pair.1, "or")
+        .ok_or_else(|| ParseError::at(s, pair.1, "expected 'range or range'"))?;
+
+    Ok(Rule {
+        name: pair.0.to_string(),
+        range_1: parse_range_inclusive(s, ranges.0)?,
+        range_2: parse_range_inclusive(s, ranges.1)?,
+    })
+}
+
+// The rules come first, then your ticket and the nearby tickets:
+// class: 1-3 or 5-7
+//
+// your ticket:
+// 7,1,14
+//
+// nearby tickets:
+// 7,3,47
+fn read_input(input: &str) -> Result<(Vec<Rule>, Vec<Vec<usize>>), ParseError> {
+    let groups = parse::groups(input);
+    let (rules, mine, nearby) = match &groups[..] {
+        [rules, mine, nearby] => (rules, mine, nearby),
+        _ => {
+            let err = format!("expected 3 sections, found {}", groups.len());
+            return Err(ParseError::new(err));
+        }
+    };
+
+    let rules = rules
+        .iter()
+        .map(|&(n, s)| read_rule(s).map_err(|e| e.on_line(n)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut tickets = Vec::new();
+    for (header, section) in [("your ticket:", mine), ("nearby tickets:", nearby)] {
+        let (n, s) = section[0];
+        if s.trim() != header {
+            let err = format!("expected '{}'", header);
+            return Err(ParseError::at(s, s.trim(), err).on_line(n));
+        }
+        for &(n, s) in &section[1..] {
+            let ticket = parse::number_list(s, s.trim(), ',').map_err(|e| e.on_line(n))?;
+            if ticket.len() != rules.len() {
+                let err = format!("expected {} fields, found {}", rules.len(), ticket.len());
+                return Err(ParseError::new(err).on_line(n));
+            }
+            tickets.push(ticket);
+        }
+    }
+    if mine.len() != 2 {
+        return Err(ParseError::new("expected exactly one ticket of yours").on_line(mine[0].0));
+    }
+
+    Ok((rules, tickets))
+}
+
+fn is_valid_any_rule(rules: &[Rule], val: &usize) -> bool {
+    rules
+        .iter()
+        .any(|r| r.range_1.contains(val) || r.range_2.contains(val))
+}
+
+fn is_ticket_valid(rules: &[Rule], ticket: &[usize]) -> bool {
+    ticket.iter().all(|val| is_valid_any_rule(rules, val))
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = (Vec<Rule>, Vec<Vec<usize>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Your ticket heads the list of tickets.
+        read_input(input)
+    }
+
+    // Start by determining which tickets are completely invalid;
+    // these are tickets that contain values which aren't valid for any field.
+    // Ignore your ticket for now.
+    // Adding together all of the invalid values produces your ticket scanning error rate.
+    fn part_1((rules, tickets): &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut rate = 0;
+        for ticket in &tickets[1..] {
+            for val in ticket {
+                if !is_valid_any_rule(rules, val) {
+                    rate += val;
+                }
+            }
+        }
+
+        // Your puzzle answer was 23044.
+        Ok(rate)
+    }
+
+    // --- Part Two ---
+
+    fn part_2((rules, tickets): &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        // Now that you've identified which tickets contain invalid values, discard those tickets entirely.
+        let tickets: Vec<&Vec<usize>> = tickets
+            .iter()
+            .filter(|t| is_ticket_valid(rules, t))
+            .collect();
+
+        // Using the valid ranges for each field, determine what order the fields appear on the tickets.
+        let mut variants: Vec<Vec<usize>> = Vec::new();
+        for rule in rules {
+            let mut feasible: Vec<usize> = Vec::new();
+            for t_index in 0..rules.len() {
+                if tickets
+                    .iter()
+                    .map(|t| &t[t_index])
+                    .all(|v| rule.range_1.contains(v) || rule.range_2.contains(v))
+                {
+                    feasible.push(t_index);
+                }
+            }
+            variants.push(feasible);
+        }
+
+        // Work out which field is which.
+        let mut remapper: HashMap<usize, usize> = HashMap::new();
+        loop {
+            let len = remapper.len();
+            for (k, v) in variants.iter().enumerate() {
+                if v.len() == 1 {
+                    remapper.insert(v[0], k);
+                }
+            }
+            if remapper.len() == len {
+                return Err("fail to work out which field is which".into());
+            }
+            if remapper.len() == variants.len() {
+                break;
+            }
+
+            for v in variants.iter_mut() {
+                v.retain(|k| !remapper.contains_key(k));
+            }
+        }
+
+        // Look for the 6 fields on your ticket that start with the word departure.
+        // What do you get if you multiply those 6 values together?
+        let product: usize = remapper
+            .iter()
+            .filter(|(_, &v)| rules[v].name.starts_with("departure"))
+            .map(|(&k, _)| tickets[0][k])
+            .product();
+
+        // Your puzzle answer was 3765150732757.
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+
+    // The second example with "departure" in the names of the class and the seat fields.
+    const DEPARTURE: &str = "\
+departure class: 0-1 or 4-19
+row: 0-5 or 8-19
+departure seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::One);
+        assert_eq!(answers.part_1, Some(Answer::Number(71)));
+        let answers = solve_example::<Puzzle>(DEPARTURE, Part::Two);
+        assert_eq!(answers.part_2, Some(Answer::Number(156)));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::ParseError;
+use crate::grid::{Dir, Grid, DIRECTIONS};
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+use std::iter;
+use std::str::FromStr;
+
+// The cube itself and its neighbours in a plane.
+fn around() -> impl Iterator<Item = Dir> {
+    iter::once((0, 0)).chain(DIRECTIONS.iter().copied())
+}
+
+// The pocket dimension as a slice of x and y for each z and w. The active cubes
+// spread at most one cube each cycle, so every cycle pads the grids by one cube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerGrid {
+    // The slices at their (z, w) position.
+    slices: Grid<Grid<bool>>,
+}
+
+// Each cube is either active (#) or inactive (.).
+impl FromStr for PowerGrid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<_> = parse::lines(s).collect();
+        let slice = Grid::parse(&lines, |ch| match ch {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        })?;
+
+        // The initial slice is the plane z = 0 (and w = 0).
+        Ok(Self {
+            slices: Grid::filled(1, 1, slice),
+        })
+    }
+}
+
+impl PowerGrid {
+    // A cycle where the w axis grows by dw on both sides; it doesn't in 3d.
+    fn next(&self, dw: usize) -> Self {
+        let first = &self.slices[(0, 0)];
+        let empty = Grid::filled(first.width() + 2, first.height() + 2, false);
+        let grid = self
+            .slices
+            .map(|slice| slice.pad(1, 1, false))
+            .pad(1, dw, empty);
+
+        let slices = grid.map_cells(|outer, slice| {
+            slice.map_cells(|inner, &active| {
+                // Each cube only ever considers its neighbors:
+                // any of the cubes where any of their coordinates differ by at most 1.
+                let mut count = 0;
+                for outer_dir in around() {
+                    let near = match grid.offset(outer, outer_dir) {
+                        Some(pos) => &grid[pos],
+                        None => continue,
+                    };
+                    for inner_dir in around() {
+                        if (outer_dir, inner_dir) == ((0, 0), (0, 0)) {
+                            continue;
+                        }
+                        if let Some(pos) = near.offset(inner, inner_dir) {
+                            count += usize::from(near[pos]);
+                        }
+                    }
+                }
+                // If a cube is active and exactly 2 or 3 of its neighbors are also active,
+                // the cube remains active. If a cube is inactive but exactly 3 of its
+                // neighbors are active, the cube becomes active.
+                count == 3 || (active && count == 2)
+            })
+        });
+
+        Self { slices }
+    }
+
+    pub fn next_3d(&self) -> Self {
+        self.next(0)
+    }
+
+    pub fn next_4d(&self) -> Self {
+        self.next(1)
+    }
+
+    pub fn active(&self) -> usize {
+        self.slices
+            .iter()
+            .map(|slice| slice.iter().filter(|&&active| active).count())
+            .sum()
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = PowerGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    // How many 3d cubes are left in the active state after the sixth cycle?
+    fn part_1(grid: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let next = grid
+            .next_3d()
+            .next_3d()
+            .next_3d()
+            .next_3d()
+            .next_3d()
+            .next_3d();
+
+        // Your puzzle answer was 401.
+        Ok(next.active())
+    }
+
+    // --- Part Two ---
+
+    // How many 4d cubes are left in the active state after the sixth cycle?
+    fn part_2(grid: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let next = grid
+            .next_4d()
+            .next_4d()
+            .next_4d()
+            .next_4d()
+            .next_4d()
+            .next_4d();
+
+        // Your puzzle answer was 2224.
+        Ok(next.active())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+.#.
+..#
+###
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(112)));
+        assert_eq!(answers.part_2, Some(Answer::Number(848)));
+    }
+}
@@ -0,0 +1,318 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+// The homework consists of a series of expressions that consist of addition (+), multiplication (*), and parentheses ((...)).
+#[derive(Debug, Clone)]
+pub enum MathToken {
+    Number(i64),
+    Plus,
+    Multiply,
+    OpenParenthesis,
+    CloseParenthesis,
+    Group(Vec<MathToken>),
+}
+
+// The groups are evaluated recursively, so their depth is limited to keep to the stack.
+pub const MAX_DEPTH: usize = 256;
+
+// Splits an expression into tokens and checks that it is well formed.
+pub fn tokenize(s: &str) -> Result<Vec<MathToken>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut expect_operand = true;
+    let mut depth = 0;
+
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            continue;
+        }
+
+        let token = match ch {
+            '+' => MathToken::Plus,
+            '*' => MathToken::Multiply,
+            '(' => MathToken::OpenParenthesis,
+            ')' => MathToken::CloseParenthesis,
+            ch if ch.is_ascii_digit() => {
+                let mut j = i + 1;
+                while let Some(&(k, _)) = chars.peek().filter(|(_, d)| d.is_ascii_digit()) {
+                    j = k + 1;
+                    chars.next();
+                }
+                MathToken::Number(parse::number(s, &s[i..j])?)
+            }
+            ch => {
+                let err = format!("unexpected character '{}'", ch);
+                return Err(ParseError::at(s, &s[i..], err));
+            }
+        };
+
+        match (&token, expect_operand) {
+            (MathToken::Number(_), true) => expect_operand = false,
+            (MathToken::OpenParenthesis, true) if depth < MAX_DEPTH => depth += 1,
+            (MathToken::OpenParenthesis, true) => {
+                let err = format!("parentheses nested deeper than {}", MAX_DEPTH);
+                return Err(ParseError::at(s, &s[i..], err));
+            }
+            (MathToken::Plus, false) | (MathToken::Multiply, false) => expect_operand = true,
+            (MathToken::CloseParenthesis, false) if depth > 0 => depth -= 1,
+            (MathToken::CloseParenthesis, false) => {
+                return Err(ParseError::at(s, &s[i..], "unmatched ')'"));
+            }
+            (_, true) => return Err(ParseError::at(s, &s[i..], "expected a number or '('")),
+            (_, false) => return Err(ParseError::at(s, &s[i..], "expected '+' or '*'")),
+        }
+        tokens.push(token);
+    }
+
+    if expect_operand {
+        Err(ParseError::at(
+            s,
+            &s[s.len()..],
+            "unexpected end of the expression",
+        ))
+    } else if depth > 0 {
+        Err(ParseError::at(s, &s[s.len()..], "missing ')'"))
+    } else {
+        Ok(tokens)
+    }
+}
+
+fn find_close_parenthesis_index(tokens: &[MathToken]) -> Option<usize> {
+    let mut level = 1;
+    for (i, t) in tokens.iter().enumerate() {
+        match t {
+            MathToken::OpenParenthesis => {
+                level += 1;
+            }
+            MathToken::CloseParenthesis => {
+                level -= 1;
+                if level == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn replace_parentheses_with_groups(tokens: &[MathToken]) -> Option<Vec<MathToken>> {
+    let mut buf = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            MathToken::OpenParenthesis => {
+                let j = find_close_parenthesis_index(&tokens[i + 1..])?;
+                buf.push(MathToken::Group(replace_parentheses_with_groups(
+                    &tokens[i + 1..i + 1 + j],
+                )?));
+                i += j + 2;
+            }
+            t => {
+                buf.push(t.clone());
+                i += 1;
+            }
+        }
+    }
+
+    Some(buf)
+}
+
+fn group_additions(tokens: &[MathToken]) -> Vec<MathToken> {
+    let mut buf = Vec::new();
+
+    for t in tokens {
+        buf.push(match t {
+            MathToken::Group(v) => MathToken::Group(group_additions(v)),
+            x => x.clone(),
+        });
+    }
+
+    // Each run of additions becomes one group, which is evaluated before the
+    // multiplications around it. Flat groups keep long sums from nesting deeply.
+    let mut products = Vec::new();
+    let mut sum = Vec::new();
+    for t in buf {
+        match t {
+            MathToken::Multiply => {
+                products.push(MathToken::Group(std::mem::take(&mut sum)));
+                products.push(t);
+            }
+            t => sum.push(t),
+        }
+    }
+    products.push(MathToken::Group(sum));
+
+    products
+}
+
+fn evaluate_operand(token: Option<&MathToken>) -> Option<i64> {
+    match token? {
+        MathToken::Number(n) => Some(*n),
+        MathToken::Group(t) => evaluate(t),
+        _ => None,
+    }
+}
+
+// Returns None if the expression is malformed or overflows.
+fn evaluate(expr: &[MathToken]) -> Option<i64> {
+    let mut iter = expr.iter();
+
+    let mut x = evaluate_operand(iter.next())?;
+    while let Some(op) = iter.next() {
+        let y = evaluate_operand(iter.next())?;
+
+        // The operators have the same precedence, and are evaluated left-to-right
+        // regardless of the order in which they appear.
+        x = match op {
+            MathToken::Plus => x.checked_add(y)?,
+            MathToken::Multiply => x.checked_mul(y)?,
+            _ => return None,
+        };
+    }
+
+    Some(x)
+}
+
+fn sum_results<I>(results: I) -> Result<i64, Box<dyn Error>>
+where
+    I: Iterator<Item = Option<i64>>,
+{
+    let mut sum: i64 = 0;
+    for (i, result) in results.enumerate() {
+        sum = result
+            .and_then(|x| sum.checked_add(x))
+            .ok_or_else(|| format!("Expression {} overflows", i + 1))?;
+    }
+    Ok(sum)
+}
+
+// The rules of the two parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precedence {
+    // The operators are evaluated left-to-right.
+    Equal,
+    // Addition is evaluated before multiplication.
+    AdditionFirst,
+}
+
+// Tokenizes an expression with its parentheses turned into groups.
+pub fn parse_expression(s: &str) -> Result<Vec<MathToken>, ParseError> {
+    let tokens = tokenize(s)?;
+    replace_parentheses_with_groups(&tokens)
+        .ok_or_else(|| ParseError::new("unbalanced parentheses"))
+}
+
+// Returns None if the expression overflows.
+pub fn evaluate_tokens(expr: &[MathToken], precedence: Precedence) -> Option<i64> {
+    match precedence {
+        Precedence::Equal => evaluate(expr),
+        Precedence::AdditionFirst => evaluate(&group_additions(expr)),
+    }
+}
+
+// Evaluates an expression like "2 * 3 + (4 * 5)".
+pub fn evaluate_expression(s: &str, precedence: Precedence) -> Result<i64, Box<dyn Error>> {
+    let expr = parse_expression(s)?;
+    Ok(evaluate_tokens(&expr, precedence).ok_or("the expression overflows")?)
+}
+
+fn read_homework(input: &str) -> Result<Vec<Vec<MathToken>>, ParseError> {
+    // Evaluate the expression on each line of the homework.
+    let mut buf = Vec::new();
+    for (n, s) in parse::lines(input) {
+        buf.push(parse_expression(s).map_err(|e| e.on_line(n))?);
+    }
+
+    Ok(buf)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Vec<MathToken>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_homework(input)
+    }
+
+    // What is the sum of the resulting values?
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let sum = sum_results(
+            input
+                .iter()
+                .map(|expr| evaluate_tokens(expr, Precedence::Equal)),
+        )?;
+
+        // Your puzzle answer was 21022630974613.
+        Ok(sum)
+    }
+
+    // --- Part Two ---
+
+    // Now, addition and multiplication have different precedence levels,
+    // but they're not the ones you're familiar with.
+    // Instead, addition is evaluated before multiplication.
+
+    // What do you get if you add up the results of evaluating
+    // the homework problems using these new rules?
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let sum = sum_results(
+            input
+                .iter()
+                .map(|expr| evaluate_tokens(expr, Precedence::AdditionFirst)),
+        )?;
+
+        // Your puzzle answer was 169899524778212.
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    #[test]
+    fn example() {
+        for (expr, value_1, value_2) in [
+            ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+            ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+            ("2 * 3 + (4 * 5)", 26, 46),
+            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+            (
+                "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+                13632,
+                23340,
+            ),
+        ] {
+            let answers = solve_example::<Puzzle>(expr, Part::Both);
+            assert_eq!(answers.part_1, Some(Answer::Number(value_1)));
+            assert_eq!(answers.part_2, Some(Answer::Number(value_2)));
+        }
+    }
+
+    #[test]
+    fn deep_expressions() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(
+            evaluate_expression(&nested(MAX_DEPTH), Precedence::AdditionFirst).unwrap(),
+            1
+        );
+        assert_eq!(
+            parse_expression(&nested(100_000)).unwrap_err().to_string(),
+            "column 257: parentheses nested deeper than 256"
+        );
+
+        let sum = vec!["1"; 100_000].join(" + ");
+        for precedence in [Precedence::Equal, Precedence::AdditionFirst] {
+            assert_eq!(evaluate_expression(&sum, precedence).unwrap(), 100_000);
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RuleItem {
+    Data(Vec<String>),
+    Link(usize),
+    Pair(Rule, Rule),
+}
+
+pub type Rule = Vec<RuleItem>;
+type Book = HashMap<usize, Rule>;
+
+// A rule like '4 1 5', '2 3 | 3 2' or '"a"'; `s` is a part of the line.
+pub fn parse_rule(line: &str, s: &str) -> Result<Rule, ParseError> {
+    if let Some(pair) = parse::pair(s, "|") {
+        Ok(vec![RuleItem::Pair(
+            parse_rule(line, pair.0)?,
+            parse_rule(line, pair.1)?,
+        )])
+    } else if let Some(data) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        // An empty string would match forever at the same place.
+        if data.is_empty() {
+            return Err(ParseError::at(line, s, "empty data"));
+        }
+        Ok(vec![RuleItem::Data(vec![data.to_string()])])
+    } else {
+        let mut rule = Vec::new();
+        for id in s.split_whitespace() {
+            rule.push(RuleItem::Link(parse::number(line, id)?));
+        }
+        if rule.is_empty() {
+            return Err(ParseError::at(line, s, "empty rule"));
+        }
+        Ok(rule)
+    }
+}
+
+fn read_rule(s: &str, book: &mut Book) -> Result<(), ParseError> {
+    let pair = parse::pair(s, ":").ok_or_else(|| ParseError::at(s, s, "expected 'id: rule'"))?;
+    let id = parse::number(s, pair.0)?;
+    if book.insert(id, parse_rule(s, pair.1)?).is_some() {
+        return Err(ParseError::at(
+            s,
+            pair.0,
+            format!("rule {} is defined twice", id),
+        ));
+    }
+    Ok(())
+}
+
+// The rules and the messages are separated by a blank line.
+fn read_input(input: &str) -> Result<(Book, Vec<String>), ParseError> {
+    let groups = parse::groups(input);
+    let (rules, messages) = match &groups[..] {
+        [rules, messages] => (rules, messages),
+        _ => {
+            let err = format!(
+                "expected rules and messages, found {} sections",
+                groups.len()
+            );
+            return Err(ParseError::new(err));
+        }
+    };
+
+    let mut book = Book::new();
+    for &(n, s) in rules {
+        read_rule(s, &mut book).map_err(|e| e.on_line(n))?;
+    }
+    let messages = messages.iter().map(|(_, s)| s.trim().to_string()).collect();
+
+    Ok((book, messages))
+}
+
+fn has_link(rule: &[RuleItem]) -> bool {
+    for item in rule {
+        match item {
+            RuleItem::Link(_) => return true,
+            RuleItem::Pair(a, b) => {
+                if has_link(a) || has_link(b) {
+                    return true;
+                }
+            }
+            RuleItem::Data(_) => {}
+        }
+    }
+    false
+}
+
+fn join_data(a: &[String], b: &[String]) -> Vec<String> {
+    if a.is_empty() {
+        b.to_vec()
+    } else if b.is_empty() {
+        a.to_vec()
+    } else {
+        let mut buf = Vec::new();
+        for sa in a {
+            for sb in b {
+                buf.push(sa.clone() + sb);
+            }
+        }
+        buf
+    }
+}
+
+// Squeeze out all possible variants
+fn extract(rule: &[RuleItem]) -> Vec<String> {
+    assert!(!has_link(rule));
+
+    let mut buf = Vec::new();
+    for item in rule {
+        match item {
+            RuleItem::Data(d) => buf = join_data(&buf, d),
+            RuleItem::Pair(a, b) => {
+                let mut tmp = join_data(&buf, &extract(a));
+                tmp.extend(join_data(&buf, &extract(b)));
+                buf = tmp;
+            }
+            RuleItem::Link(_) => unreachable!(),
+        }
+    }
+    buf
+}
+
+fn is_message_valid(rule: &[RuleItem], mut s: &str) -> bool {
+    for r in rule {
+        if let RuleItem::Data(d) = r {
+            match d.iter().find(|&x| s.starts_with(x)) {
+                Some(m) => s = &s[m.len()..],
+                None => return false,
+            }
+        } else {
+            unreachable!();
+        }
+    }
+    s.is_empty()
+}
+
+fn replace_sub_rule(rule: &mut [RuleItem], rule_id: usize, data: &[String]) {
+    for item in rule {
+        if let RuleItem::Pair(a, b) = item {
+            replace_sub_rule(a, rule_id, data);
+            replace_sub_rule(b, rule_id, data);
+        } else if let RuleItem::Link(id) = item {
+            if *id == rule_id {
+                *item = RuleItem::Data(data.to_vec());
+            }
+        }
+    }
+}
+
+fn get_valid_rule(book: &mut Book) -> Option<(&usize, &Rule)> {
+    book.iter().find(|e| !has_link(e.1))
+}
+
+fn replace_rule(book: &mut Book, rule_id: usize, data: &[String]) {
+    for rule in book.values_mut() {
+        replace_sub_rule(rule, rule_id, data);
+    }
+}
+
+fn match_start<'a>(rule: &[String], mut s: &'a str) -> (usize, &'a str) {
+    let mut count = 0;
+    while let Some(r) = rule.iter().find(|&r| s.starts_with(r)) {
+        s = &s[r.len()..];
+        count += 1;
+    }
+    (count, s)
+}
+
+fn match_end<'a>(rule: &[String], mut s: &'a str) -> (usize, &'a str) {
+    let mut count = 0;
+    while let Some(r) = rule.iter().find(|&r| s.ends_with(r)) {
+        s = &s[..s.len() - r.len()];
+        count += 1;
+    }
+    (count, s)
+}
+
+// The rules with all the links resolved, and the received messages.
+pub struct Transmission {
+    rule_0: Rule,
+    // Keep rules 31 and 42 for part 2.
+    rule_42: Option<Vec<String>>,
+    rule_31: Option<Vec<String>>,
+    messages: Vec<String>,
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Transmission;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (mut book, messages) = read_input(input)?;
+
+        let mut rule_0 = book
+            .remove(&0)
+            .ok_or_else(|| ParseError::new("Rule Zero not found"))?;
+        let mut rule_42: Option<Vec<String>> = None;
+        let mut rule_31: Option<Vec<String>> = None;
+
+        while let Some((&rule_id, rule)) = get_valid_rule(&mut book) {
+            let data = extract(rule);
+
+            book.remove(&rule_id);
+            replace_rule(&mut book, rule_id, &data);
+            replace_sub_rule(&mut rule_0, rule_id, &data);
+
+            if rule_id == 42 {
+                rule_42 = Some(data);
+            } else if rule_id == 31 {
+                rule_31 = Some(data);
+            }
+        }
+
+        // Rule Zero has to boil down to a sequence of plain data.
+        if !rule_0.iter().all(|item| matches!(item, RuleItem::Data(_))) {
+            return Err(ParseError::new(
+                "Rule Zero refers to undefined or looping rules",
+            ));
+        }
+
+        Ok(Transmission {
+            rule_0,
+            rule_42,
+            rule_31,
+            messages,
+        })
+    }
+
+    // How many messages completely match rule 0?
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let count = input
+            .messages
+            .iter()
+            .filter(|&msg| is_message_valid(&input.rule_0, msg))
+            .count();
+
+        // Your puzzle answer was 178.
+        Ok(count)
+    }
+
+    // --- Part Two ---
+
+    // Replace rules 8: 42 and 11: 42 31 with the following:
+    // 8: 42 | 42 8
+    // 11: 42 31 | 42 11 31
+    // Now, the rules do contain loops! But the message length is finite, so:
+    // 0: 8 11
+    // 8: 42 | 42 42 | 42 42 42 | and so on
+    // 11: 42 31 | 42 42 31 31 | 42 42 42 31 31 31 | and so on
+    //  =>
+    // 0: 42 * (2 + n) 31 * (1 + m)
+    // where n >= 0 and n >= m
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let rule_42 = input.rule_42.as_ref().ok_or("Rule 42 not found")?;
+        let rule_31 = input.rule_31.as_ref().ok_or("Rule 31 not found")?;
+
+        let count = input
+            .messages
+            .iter()
+            .filter(|&msg| {
+                let (i, s) = match_start(rule_42, msg);
+                let (j, s) = match_end(rule_31, s);
+                s.is_empty() && i >= 2 && j >= 1 && i > j
+            })
+            .count();
+
+        // Your puzzle answer was 346.
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = r#"
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb
+"#;
+
+    const LOOPS: &str = r#"
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+"#;
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::One);
+        assert_eq!(answers.part_1, Some(Answer::Number(2)));
+        let answers = solve_example::<Puzzle>(LOOPS, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(3)));
+        assert_eq!(answers.part_2, Some(Answer::Number(12)));
+    }
+
+    #[test]
+    fn rules() {
+        let line = "1: 2 3 | 3 2";
+        let rule = parse_rule(line, &line[3..]).unwrap();
+        assert!(matches!(&rule[..], [RuleItem::Pair(a, b)] if a.len() == 2 && b.len() == 2));
+        assert!(parse_rule("\"a\"", "\"a\"").is_ok());
+        assert!(parse_rule("\"\"", "\"\"").is_err());
+        assert!(parse_rule("1 |", "1 |").is_err());
+    }
+}
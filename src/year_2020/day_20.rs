@@ -0,0 +1,435 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::error::Error;
+
+const TILE_SIZE: usize = 10;
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    pub fn next(&self, dx: i32, dy: i32) -> Self {
+        Self {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+    pub fn left(&self) -> Self {
+        self.next(-1, 0)
+    }
+    pub fn right(&self) -> Self {
+        self.next(1, 0)
+    }
+    pub fn up(&self) -> Self {
+        self.next(0, -1)
+    }
+    pub fn down(&self) -> Self {
+        self.next(0, 1)
+    }
+}
+
+pub struct Tile {
+    id: usize,
+    data: Grid<char>,
+}
+
+// The top border of a matches the bottom border of b.
+fn up_down_equal(a: &Grid<char>, b: &Grid<char>) -> bool {
+    a.row(0) == b.row(b.height() - 1)
+}
+
+// The left border of a matches the right border of b.
+fn left_right_equal(a: &Grid<char>, b: &Grid<char>) -> bool {
+    a.column(0).eq(b.column(b.width() - 1))
+}
+
+impl Tile {
+    pub fn can_add_up(&self, data: &Grid<char>) -> bool {
+        up_down_equal(&self.data, data)
+    }
+    pub fn can_add_down(&self, data: &Grid<char>) -> bool {
+        up_down_equal(data, &self.data)
+    }
+    pub fn can_add_left(&self, data: &Grid<char>) -> bool {
+        left_right_equal(&self.data, data)
+    }
+    pub fn can_add_right(&self, data: &Grid<char>) -> bool {
+        left_right_equal(data, &self.data)
+    }
+}
+
+#[derive(Default)]
+pub struct Image {
+    tiles: HashMap<Position, Tile>,
+}
+
+impl Image {
+    pub fn new() -> Self {
+        Self {
+            tiles: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, pos: Position, id: usize, data: Grid<char>) {
+        assert!(!self.tiles.contains_key(&pos));
+        self.tiles.insert(pos, Tile { id, data });
+    }
+
+    pub fn can_add(&mut self, data: &Grid<char>) -> Option<Position> {
+        let is_free = |pos: Position| !self.tiles.contains_key(&pos);
+        for (pos, t) in &self.tiles {
+            if t.can_add_up(data) && is_free(pos.up()) {
+                return Some(pos.up());
+            }
+            if t.can_add_down(data) && is_free(pos.down()) {
+                return Some(pos.down());
+            }
+            if t.can_add_left(data) && is_free(pos.left()) {
+                return Some(pos.left());
+            }
+            if t.can_add_right(data) && is_free(pos.right()) {
+                return Some(pos.right());
+            }
+        }
+        None
+    }
+
+    // The tiles have to fill a square without gaps.
+    pub fn is_square(&self) -> bool {
+        let xs = self.tiles.keys().map(|pos| pos.x);
+        let ys = self.tiles.keys().map(|pos| pos.y);
+        match (xs.clone().min(), xs.max(), ys.clone().min(), ys.max()) {
+            (Some(x0), Some(x1), Some(y0), Some(y1)) => {
+                let (w, h) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+                w == h && w * h == self.tiles.len()
+            }
+            _ => false,
+        }
+    }
+
+    pub fn top_left(&self) -> Option<(&Position, &Tile)> {
+        self.tiles.iter().find(|(pos, _)| {
+            !self.tiles.contains_key(&pos.left()) && !self.tiles.contains_key(&pos.up())
+        })
+    }
+
+    pub fn bottom_right(&self) -> Option<(&Position, &Tile)> {
+        self.tiles.iter().find(|(pos, _)| {
+            !self.tiles.contains_key(&pos.right()) && !self.tiles.contains_key(&pos.down())
+        })
+    }
+
+    pub fn get_image(&self) -> Grid<char> {
+        let data_size = TILE_SIZE - 2;
+
+        assert!(!self.tiles.is_empty());
+        let p0 = self.top_left().expect("no tiles").0;
+        let p1 = self.bottom_right().expect("no tiles").0;
+
+        assert!(p1.x >= p0.x);
+        assert!(p1.y >= p0.y);
+        let w = (1 + p1.x - p0.x) as usize;
+        let h = (1 + p1.y - p0.y) as usize;
+
+        let mut image = Grid::filled(w * data_size, h * data_size, '.');
+        for row in 0..h {
+            for col in 0..w {
+                let tile = self
+                    .tiles
+                    .get(&p0.next(col as i32, row as i32))
+                    .expect("invalid image");
+                // The borders of the tiles are not part of the actual image.
+                let data = tile.data.crop((1, 1), data_size, data_size);
+                for ((x, y), &ch) in data.cells() {
+                    image[(col * data_size + x, row * data_size + y)] = ch;
+                }
+            }
+        }
+        image
+    }
+
+    pub fn corners(&self) -> Vec<(&Position, &Tile)> {
+        self.tiles
+            .iter()
+            .filter(|(pos, _)| {
+                let mut count = 0;
+                if self.tiles.contains_key(&pos.up()) {
+                    count += 1;
+                }
+                if self.tiles.contains_key(&pos.down()) {
+                    count += 1;
+                }
+                if self.tiles.contains_key(&pos.left()) {
+                    count += 1;
+                }
+                if self.tiles.contains_key(&pos.right()) {
+                    count += 1;
+                }
+                count == 2
+            })
+            .collect()
+    }
+}
+
+// Each tile starts with a "Tile <id>:" header followed by its rows of '.' and '#'.
+// Tiles are separated by blank lines.
+fn read_tile(lines: &[parse::Line]) -> Result<Tile, ParseError> {
+    let (n, s) = lines[0];
+    let t = s.trim();
+    let id = t
+        .strip_prefix("Tile ")
+        .and_then(|t| t.strip_suffix(':'))
+        .ok_or_else(|| ParseError::at(s, t, "expected 'Tile <id>:'").on_line(n))?;
+    let id = parse::number(s, id.trim()).map_err(|e| e.on_line(n))?;
+
+    let data = Grid::parse(&lines[1..], |ch| matches!(ch, '.' | '#').then_some(ch))
+        .map_err(|e| e.on_line(n))?;
+    if data.width() != TILE_SIZE || data.height() != TILE_SIZE {
+        let err = format!("tile {} is not {}x{}", id, TILE_SIZE, TILE_SIZE);
+        return Err(ParseError::new(err).on_line(n));
+    }
+
+    Ok(Tile { id, data })
+}
+
+fn read_input(input: &str) -> Result<Vec<Tile>, ParseError> {
+    parse::groups(input)
+        .iter()
+        .map(|group| read_tile(group))
+        .collect()
+}
+
+fn is_one(ch: &char) -> bool {
+    *ch == '#'
+}
+
+// The spaces of the pattern can be anything, only the '#' need to match.
+fn count_monsters(sea: &Grid<char>, pattern: &Grid<char>) -> usize {
+    let spots: Vec<Pos> = pattern
+        .cells()
+        .filter(|(_, ch)| is_one(ch))
+        .map(|(pos, _)| pos)
+        .collect();
+
+    sea.positions()
+        .filter(|&(x, y)| {
+            spots
+                .iter()
+                .all(|&(dx, dy)| sea.get((x + dx, y + dy)).is_some_and(is_one))
+        })
+        .count()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Image;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // Assemble the tiles into an image.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tiles = read_input(input)?;
+
+        let mut image = Image::new();
+        if let Some(tile) = tiles.pop() {
+            image.insert(Position { x: 0, y: 0 }, tile.id, tile.data);
+        }
+
+        let mut trans: Vec<(usize, [Grid<char>; 8])> = tiles
+            .into_iter()
+            .map(|t| (t.id, t.data.transforms()))
+            .collect();
+
+        while !trans.is_empty() {
+            let len = trans.len();
+            for i in (0..trans.len()).rev() {
+                for j in 0..trans[i].1.len() {
+                    if let Some(pos) = image.can_add(&trans[i].1[j]) {
+                        let (id, tr) = trans.remove(i);
+                        image.insert(pos, id, tr[j].clone());
+                        break;
+                    }
+                }
+            }
+            if trans.len() == len {
+                let ids: Vec<String> = trans.iter().map(|(id, _)| id.to_string()).collect();
+                let err = format!("tiles {} don't fit into the image", ids.join(", "));
+                return Err(ParseError::new(err));
+            }
+        }
+
+        if !image.is_square() {
+            return Err(ParseError::new("the tiles don't form a square image"));
+        }
+        Ok(image)
+    }
+
+    // What do you get if you multiply together the IDs of the four corner tiles?
+    fn part_1(image: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let corners = image.corners();
+        if corners.len() != 4 {
+            return Err(format!("expected 4 corners, found {}", corners.len()).into());
+        }
+        let prod: usize = corners.iter().map(|(_, tile)| tile.id).product();
+
+        // Your puzzle answer was 111936085519519.
+        Ok(prod)
+    }
+
+    // --- Part Two ---
+
+    // Now, you're ready to search for sea monsters!
+    // When looking for this pattern in the image, the spaces can be anything; only the # need to match.
+    fn part_2(image: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let pattern = [
+            "                  # ",
+            "#    ##    ##    ###",
+            " #  #  #  #  #  #   ",
+        ];
+        let pattern = Grid::from_rows(pattern.iter().map(|s| s.chars().collect()).collect());
+
+        for sea in image.get_image().transforms() {
+            let count = count_monsters(&sea, &pattern);
+            if count > 0 {
+                // How many '#' are not part of a sea monster?
+                let s = sea.iter().filter(|ch| is_one(ch)).count();
+                let m = pattern.iter().filter(|ch| is_one(ch)).count();
+                let roughness = s - m * count;
+
+                // Your puzzle answer was 1792.
+                return Ok(roughness);
+            }
+        }
+        Err("no sea monsters found".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###...
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(20899048083289)));
+        assert_eq!(answers.part_2, Some(Answer::Number(273)));
+    }
+}
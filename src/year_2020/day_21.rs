@@ -0,0 +1,195 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+// mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+impl FromStr for Food {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let a_start = "(contains";
+        let a_end = ")";
+        let a_pos = s
+            .find(a_start)
+            .zip(s.rfind(a_end))
+            .filter(|(start, end)| start < end)
+            .ok_or_else(|| ParseError::at(s, s, "expected '(contains <allergens>)'"))?;
+
+        let food = Self {
+            ingredients: s[..a_pos.0]
+                .trim()
+                .split_ascii_whitespace()
+                .map(|s| s.to_string())
+                .collect(),
+            allergens: s[a_pos.0 + a_start.len()..a_pos.1]
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+        };
+        if food.ingredients.is_empty() {
+            return Err(ParseError::at(s, s, "no ingredients"));
+        }
+        if food.allergens.is_empty() {
+            return Err(ParseError::at(s, &s[a_pos.0..], "no allergens"));
+        }
+        Ok(food)
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Food>, ParseError> {
+    let mut buf = Vec::new();
+    for (n, s) in parse::lines(input) {
+        buf.push(s.parse().map_err(|e: ParseError| e.on_line(n))?);
+    }
+
+    Ok(buf)
+}
+
+// Determine which ingredients can't possibly contain any of the allergens in any food in your list.
+fn find_inert_ingredients(foods: &[Food]) -> HashSet<&String> {
+    let mut ingredients = HashSet::new();
+    let mut allergens = HashSet::new();
+
+    for food in foods {
+        ingredients.extend(&food.ingredients);
+        allergens.extend(&food.allergens);
+    }
+
+    let mut inert_ingredients = HashSet::new();
+
+    // Each allergen is found in exactly one ingredient.
+    // Each ingredient contains zero or one allergen.
+    for ingredient in ingredients {
+        let mut a_count = 0;
+        for allergen in &allergens {
+            for food in foods {
+                if !food.ingredients.contains(ingredient) && food.allergens.contains(allergen) {
+                    // ingredients cannot have any allergen
+                    a_count += 1;
+                    break;
+                }
+            }
+        }
+
+        if a_count == allergens.len() {
+            inert_ingredients.insert(ingredient);
+        }
+    }
+
+    inert_ingredients
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<Food>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    // Determine which ingredients cannot possibly contain any of the allergens in your list.
+    // How many times do any of those ingredients appear?
+    fn part_1(foods: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let i_count = find_inert_ingredients(foods)
+            .into_iter()
+            .map(|ingredient| {
+                foods
+                    .iter()
+                    .filter(|&food| food.ingredients.contains(ingredient))
+                    .count()
+            })
+            .sum();
+
+        // Your puzzle answer was 2211.
+        Ok(i_count)
+    }
+
+    // --- Part Two ---
+
+    fn part_2(foods: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let inert_ingredients = find_inert_ingredients(foods);
+
+        let mut allergens: HashMap<&str, Vec<&str>> = HashMap::new();
+        for food in foods {
+            let dangerous_ingredients: HashSet<&str> = food
+                .ingredients
+                .iter()
+                .filter(|i| !inert_ingredients.contains(i))
+                .map(String::as_str)
+                .collect();
+            for allergen in &food.allergens {
+                if let Some(ingredient_set) = allergens.get_mut(allergen.as_str()) {
+                    ingredient_set.retain(|&i| dangerous_ingredients.contains(i));
+                } else {
+                    allergens.insert(allergen, dangerous_ingredients.iter().copied().collect());
+                }
+            }
+        }
+
+        // You should have enough information to figure out which ingredient contains which allergen.
+        let mut dangers: Vec<(&str, &str)> = Vec::with_capacity(allergens.len());
+        while !allergens.is_empty() {
+            let len = dangers.len();
+            for (k, v) in allergens.iter().filter(|(_, v)| v.len() == 1) {
+                dangers.push((v[0], k));
+            }
+            if dangers.len() == len {
+                return Err("fail to figure out which ingredient contains which allergen".into());
+            }
+            allergens.retain(|_, v| v.len() > 1);
+
+            for v in allergens.values_mut() {
+                v.retain(|&k| !dangers.iter().any(|d| d.0 == k));
+            }
+        }
+        dangers.sort_by(|a, b| a.1.cmp(b.1));
+
+        // Arrange the ingredients alphabetically by their allergen and separate them by commas
+        // to produce your canonical dangerous ingredient list.
+        let list = dangers
+            .into_iter()
+            .map(|d| d.0)
+            .collect::<Vec<&str>>()
+            .join(",");
+
+        // Your puzzle answer was 'vv,nlxsmb,rnbhjk,bvnkk,ttxvphb,qmkz,trmzkcfg,jpvz'.
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(5)));
+        assert_eq!(
+            answers.part_2,
+            Some(Answer::Text(String::from("mxmxvkd,sqjhc,fvjkl")))
+        );
+    }
+}
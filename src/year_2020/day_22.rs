@@ -1,4 +1,8 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
 use std::collections::HashSet;
+use std::error::Error;
 
 fn game_score(cards: &[usize]) -> usize {
     cards
@@ -28,11 +32,11 @@ fn combat(mut player_1: Vec<usize>, mut player_2: Vec<usize>) -> (bool, Vec<usiz
     }
 
     // Once the game ends, you can calculate the winning player's score.
-    return if player_1.is_empty() {
+    if player_1.is_empty() {
         (false, player_2)
     } else {
         (true, player_1)
-    };
+    }
 }
 
 fn recursive_combat(mut player_1: Vec<usize>, mut player_2: Vec<usize>) -> (bool, Vec<usize>) {
@@ -89,41 +93,94 @@ fn recursive_combat(mut player_1: Vec<usize>, mut player_2: Vec<usize>) -> (bool
         }
     }
 
-    return if player_1.is_empty() {
+    if player_1.is_empty() {
         (false, player_2)
     } else {
         (true, player_1)
-    };
+    }
 }
 
-pub fn run() {
-    println!("--- Day 22: Crab Combat ---");
-
-    let player_1 = vec![
-        50, 19, 40, 22, 7, 4, 3, 16, 34, 45, 46, 39, 44, 32, 20, 29, 15, 35, 41, 2, 21, 28, 6, 26,
-        48,
-    ];
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = (Vec<usize>, Vec<usize>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // Each deck starts with a "Player N:" header, the top card comes first.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut decks: Vec<Vec<usize>> = Vec::new();
+        let mut cards = HashSet::new();
+        for group in parse::groups(input) {
+            let (n, line) = group[0];
+            if !line.trim().starts_with("Player") {
+                return Err(ParseError::new("missing player header").on_line(n));
+            }
+            let mut deck = Vec::new();
+            for &(n, line) in &group[1..] {
+                let t = line.trim();
+                let card = parse::number(line, t).map_err(|e| e.on_line(n))?;
+                // The cards are all different, so no round can end in a draw.
+                if !cards.insert(card) {
+                    let err = format!("card {} is dealt twice", card);
+                    return Err(ParseError::at(line, t, err).on_line(n));
+                }
+                deck.push(card);
+            }
+            decks.push(deck);
+        }
 
-    let player_2 = vec![
-        14, 9, 37, 47, 38, 27, 30, 24, 36, 31, 43, 42, 11, 17, 18, 10, 12, 5, 33, 25, 8, 23, 1, 13,
-        49,
-    ];
+        if decks.len() != 2 {
+            let err = format!("expected 2 decks, found {}", decks.len());
+            return Err(ParseError::new(err));
+        }
+        let player_2 = decks.pop().unwrap_or_default();
+        let player_1 = decks.pop().unwrap_or_default();
+        Ok((player_1, player_2))
+    }
 
-    let game = combat(player_1.clone(), player_2.clone());
+    fn part_1((player_1, player_2): &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let game = combat(player_1.clone(), player_2.clone());
 
-    // Your puzzle answer was 32083.
-    println!(
-        "Part 1. The winning player's score: {}",
-        game_score(&game.1)
-    );
+        // Your puzzle answer was 32083.
+        Ok(game_score(&game.1))
+    }
 
     // --- Part Two ---
 
-    let game = recursive_combat(player_1, player_2);
+    fn part_2((player_1, player_2): &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let game = recursive_combat(player_1.clone(), player_2.clone());
+
+        // Your puzzle answer was 35495.
+        Ok(game_score(&game.1))
+    }
+}
 
-    // Your puzzle answer was 35495.
-    println!(
-        "Part 2. The winning player's score: {}",
-        game_score(&game.1)
-    );
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(306)));
+        assert_eq!(answers.part_2, Some(Answer::Number(291)));
+    }
 }
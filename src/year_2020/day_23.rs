@@ -1,7 +1,11 @@
+use crate::error::ParseError;
+use crate::puzzle::Solver;
+use std::error::Error;
 use std::fmt;
 
-#[derive(Debug)]
-struct CupCircle {
+// The cups are linked by their labels: nexts[cup - 1] is the cup clockwise of the cup.
+#[derive(Debug, Clone)]
+pub struct CupCircle {
     current_cup: usize,
     nexts: Vec<usize>,
 }
@@ -31,12 +35,13 @@ impl CupCircle {
         self.nexts[Self::cup_index(cup)] = next_cup;
     }
 
+    // The digits of the order label the first cups, the others follow in increasing order.
     pub fn new(mut order: usize, size: usize) -> Self {
         let mut cups = Vec::with_capacity(size);
 
         while order > 0 {
             cups.push(order % 10);
-            order = order / 10;
+            order /= 10;
         }
         cups.reverse();
 
@@ -105,34 +110,79 @@ impl CupCircle {
     }
 }
 
-pub fn run() {
-    println!("--- Day 23: Crab Cups ---");
-
-    // Your puzzle input is 394618527.
-    let input = 394618527;
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = usize;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // The cups are labeled with the digits 1 to 9 in clockwise order.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let labels = input.trim();
+        let mut digits: Vec<char> = labels.chars().collect();
+        digits.sort_unstable();
+        if digits.iter().copied().ne("123456789".chars()) {
+            let err = format!(
+                "expected the digits 1 to 9 in any order, found '{}'",
+                labels
+            );
+            return Err(ParseError::new(err));
+        }
 
-    let mut circle = CupCircle::new(input, 9);
-    for _ in 0..100 {
-        circle.next_move();
+        labels
+            .parse()
+            .map_err(|_| ParseError::new(format!("invalid cup labels: '{}'", labels)))
     }
 
-    let order = circle.after_one_order();
+    fn part_1(&input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut circle = CupCircle::new(input, 9);
+        for _ in 0..100 {
+            circle.next_move();
+        }
 
-    // Your puzzle answer was 78569234.
-    println!("Part 1. The labels on the cups after cup 1: {}", order);
+        // Your puzzle answer was 78569234.
+        Ok(circle.after_one_order())
+    }
 
     // --- Part Two ---
 
     // one million cups
     // ten million moves
-    let mut circle = CupCircle::new(input, 1_000_000);
-    for _ in 0..10_000_000 {
-        circle.next_move();
+    fn part_2(&input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut circle = CupCircle::new(input, 1_000_000);
+        for _ in 0..10_000_000 {
+            circle.next_move();
+        }
+
+        // The crab is going to hide your stars under the two cups that will end up immediately clockwise of cup 1.
+        let cups = circle.two_cups_after_one();
+
+        // Your puzzle answer was 565615814504.
+        Ok(cups.0 * cups.1)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
 
-    // The crab is going to hide your stars under the two cups that will end up immediately clockwise of cup 1.
-    let cups = circle.two_cups_after_one();
+    const EXAMPLE: &str = "\
+389125467
+";
 
-    // Your puzzle answer was 565615814504.
-    println!("Part 2. Two cups product: {}", cups.0 * cups.1);
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::One);
+        assert_eq!(answers.part_1, Some(Answer::Number(67384529)));
+    }
+
+    // Tens of millions of moves take long in a debug build.
+    #[test]
+    #[ignore]
+    fn example_part_2() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Two);
+        assert_eq!(answers.part_2, Some(Answer::Number(149245887792)));
+    }
 }
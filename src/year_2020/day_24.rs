@@ -0,0 +1,298 @@
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::collections::HashSet;
+use std::error::Error;
+use std::str::FromStr;
+
+// Because the tiles are hexagonal, every tile has six neighbors:
+// east, southeast, southwest, west, northwest, and northeast.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HexDir {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDir {
+    // Directions are given in your list, respectively, as e, se, sw, w, nw, and ne.
+    pub fn name(&self) -> &str {
+        match self {
+            HexDir::East => "e",
+            HexDir::SouthEast => "se",
+            HexDir::SouthWest => "sw",
+            HexDir::West => "w",
+            HexDir::NorthWest => "nw",
+            HexDir::NorthEast => "ne",
+        }
+    }
+
+    pub fn opposite(&self) -> HexDir {
+        match self {
+            HexDir::East => HexDir::West,
+            HexDir::SouthEast => HexDir::NorthWest,
+            HexDir::SouthWest => HexDir::NorthEast,
+            HexDir::West => HexDir::East,
+            HexDir::NorthWest => HexDir::SouthEast,
+            HexDir::NorthEast => HexDir::SouthWest,
+        }
+    }
+
+    // The step as (dx, dy), see `HexPos`.
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            HexDir::East => (2, 0),
+            HexDir::NorthEast => (1, 1),
+            HexDir::NorthWest => (-1, 1),
+            HexDir::West => (-2, 0),
+            HexDir::SouthWest => (-1, -1),
+            HexDir::SouthEast => (1, -1),
+        }
+    }
+
+    pub fn values() -> [HexDir; 6] {
+        [
+            HexDir::East,
+            HexDir::NorthEast,
+            HexDir::NorthWest,
+            HexDir::West,
+            HexDir::SouthWest,
+            HexDir::SouthEast,
+        ]
+    }
+}
+
+// The reference tile is at the origin. A step east or west moves two columns,
+// the other steps move one column and one row.
+#[derive(Debug, Copy, Clone, Default, Hash, Eq, PartialEq)]
+pub struct HexPos {
+    x: i32,
+    y: i32,
+}
+
+impl HexPos {
+    pub fn coordinates(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+
+    pub fn next(&mut self, dir: &HexDir) {
+        let (dx, dy) = dir.offset();
+        self.x += dx;
+        self.y += dy;
+    }
+
+    pub fn neighbor(&self, dir: &HexDir) -> Self {
+        let mut pos = *self;
+        pos.next(dir);
+        pos
+    }
+}
+
+// Each line is a sequence of directions without delimiters: e, se, sw, w, nw, and ne.
+impl FromStr for HexPos {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut s = line.trim();
+        let mut pos = HexPos::default();
+        while !s.is_empty() {
+            let values = HexDir::values();
+            let d = values
+                .iter()
+                .find(|&d| s.starts_with(d.name()))
+                .ok_or_else(|| ParseError::at(line, s, "expected e, se, sw, w, nw or ne"))?;
+            s = &s[d.name().len()..];
+            pos.next(d);
+        }
+        Ok(pos)
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<HexPos>, ParseError> {
+    let mut buf = Vec::new();
+    for (n, s) in parse::lines(input) {
+        buf.push(s.parse().map_err(|e: ParseError| e.on_line(n))?);
+    }
+
+    Ok(buf)
+}
+
+// The black tiles on a grid of the same columns and rows as `HexPos`.
+// Only every other cell is a tile, but the cells in between never turn black:
+// all their neighbors are cells in between too.
+fn floor(blacks: &HashSet<HexPos>) -> Grid<bool> {
+    let xs = blacks.iter().map(|pos| pos.x);
+    let ys = blacks.iter().map(|pos| pos.y);
+    match (xs.clone().min(), xs.max(), ys.clone().min(), ys.max()) {
+        (Some(x0), Some(x1), Some(y0), Some(y1)) => {
+            let (w, h) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+            let mut floor = Grid::filled(w, h, false);
+            for pos in blacks {
+                floor[((pos.x - x0) as usize, (pos.y - y0) as usize)] = true;
+            }
+            floor
+        }
+        _ => Grid::filled(0, 0, false),
+    }
+}
+
+fn next_exhibit(floor: &Grid<bool>) -> Grid<bool> {
+    // The black tiles spread at most one tile each day.
+    let floor = floor.pad(2, 1, false);
+    let mut next = floor.clone();
+
+    for (pos, &black) in floor.cells() {
+        let count = HexDir::values()
+            .iter()
+            .map(|dir| dir.offset())
+            .filter_map(|(dx, dy)| floor.offset(pos, (dx as isize, dy as isize)))
+            .filter(|&p| floor[p])
+            .count();
+        next[pos] = if black {
+            // Any black tile with zero or more than 2 black tiles immediately adjacent to it is flipped to white.
+            count > 0 && count <= 2
+        } else {
+            // Any white tile with exactly 2 black tiles immediately adjacent to it is flipped to black.
+            count == 2
+        };
+    }
+
+    next
+}
+
+// Each line in the list identifies a single tile that needs to be flipped.
+fn flip_tiles(input: &[HexPos]) -> HashSet<HexPos> {
+    let mut black_tiles: HashSet<HexPos> = HashSet::new();
+
+    for pos in input {
+        if black_tiles.contains(pos) {
+            black_tiles.remove(pos);
+        } else {
+            black_tiles.insert(*pos);
+        }
+    }
+
+    black_tiles
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = Vec<HexPos>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        // Your puzzle answer was 307.
+        Ok(flip_tiles(input).len())
+    }
+
+    // --- Part Two ---
+
+    // How many tiles will be black after 100 days?
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut floor = floor(&flip_tiles(input));
+        for _ in 0..100 {
+            floor = next_exhibit(&floor);
+        }
+
+        // Your puzzle answer was 3787.
+        Ok(floor.iter().filter(|&&black| black).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop;
+    use crate::puzzle::{solve_example, Answer, Part};
+    use crate::rng::Rng;
+
+    fn path(rng: &mut Rng) -> Vec<HexDir> {
+        let len = rng.below(40);
+        (0..len).map(|_| *rng.choose(&HexDir::values())).collect()
+    }
+
+    fn walk(path: &[HexDir]) -> HexPos {
+        let mut pos = HexPos::default();
+        for dir in path {
+            pos.next(dir);
+        }
+        pos
+    }
+
+    #[test]
+    fn path_properties() {
+        prop::check(
+            "opposite directions cancel",
+            |rng| {
+                let there = path(rng);
+                let mut back: Vec<HexDir> = there.iter().map(HexDir::opposite).collect();
+                rng.shuffle(&mut back);
+                (there, back)
+            },
+            |(there, back)| walk(&[there.clone(), back.clone()].concat()) == HexPos::default(),
+        );
+        prop::check(
+            "the order of the steps doesn't matter",
+            |rng| {
+                let path = path(rng);
+                let mut shuffled = path.clone();
+                rng.shuffle(&mut shuffled);
+                (path, shuffled)
+            },
+            |(path, shuffled)| walk(path) == walk(shuffled),
+        );
+        prop::check("a path reads back as written", path, |path| {
+            let line: String = path.iter().map(HexDir::name).collect();
+            line.parse::<HexPos>() == Ok(walk(path))
+        });
+        prop::check("six different neighbors", path, |path| {
+            let pos = walk(path);
+            let neighbors: HashSet<HexPos> =
+                HexDir::values().iter().map(|d| pos.neighbor(d)).collect();
+            neighbors.len() == 6
+                && HexDir::values()
+                    .iter()
+                    .all(|d| pos.neighbor(d).neighbor(&d.opposite()) == pos)
+        });
+    }
+
+    const EXAMPLE: &str = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(10)));
+        assert_eq!(answers.part_2, Some(Answer::Number(2208)));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::puzzle::Solver;
+use std::error::Error;
+
+// The handshake works on the remainders after dividing by this prime.
+const MODULUS: usize = 20201227;
+
+fn transform(subject_number: usize, loop_size: usize) -> usize {
+    // To transform a subject number, start with the value 1.
+    let mut value = 1;
+    // Then, a number of times called the loop size, perform the following steps:
+    for _ in 0..loop_size {
+        // Set the value to itself multiplied by the subject number.
+        value *= subject_number;
+        // Set the value to the remainder after dividing the value by 20201227.
+        value %= MODULUS;
+    }
+    value
+}
+
+fn get_loop_size(subject_number: usize, result_number: usize) -> usize {
+    // To transform a subject number, start with the value 1.
+    let mut value = 1;
+    // Then, a number of times called the loop size, perform the following steps:
+    let mut loop_size = 0;
+    loop {
+        if value == result_number {
+            break;
+        }
+        // Set the value to itself multiplied by the subject number.
+        value *= subject_number;
+        // Set the value to the remainder after dividing the value by 20201227.
+        value %= MODULUS;
+        loop_size += 1;
+    }
+
+    loop_size
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Input = (usize, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const HAS_PART_2: bool = false;
+
+    // The card's public key comes first, the door's one second.
+    // The subject number 7 reaches every key from 1 to MODULUS - 1, and only those.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut keys = Vec::new();
+        for (n, line) in parse::lines(input) {
+            let key: usize = parse::number(line, line.trim()).map_err(|e| e.on_line(n))?;
+            if key == 0 || key >= MODULUS {
+                let err = format!("the public key {} is not from 1 to {}", key, MODULUS - 1);
+                return Err(ParseError::at(line, line.trim(), err).on_line(n));
+            }
+            keys.push(key);
+        }
+        let (card_pub_key, door_pub_key) = match keys[..] {
+            [card, door] => (card, door),
+            _ => {
+                let err = format!("expected 2 public keys, found {}", keys.len());
+                return Err(ParseError::new(err));
+            }
+        };
+
+        Ok((card_pub_key, door_pub_key))
+    }
+
+    // What encryption key is the handshake trying to establish?
+    fn part_1(
+        &(card_pub_key, door_pub_key): &Self::Input,
+    ) -> Result<Self::Answer1, Box<dyn Error>> {
+        let card_loop_size = get_loop_size(7, card_pub_key);
+        let door_loop_size = get_loop_size(7, door_pub_key);
+
+        // At this point, you can use either device's loop size with the other device's public key to calculate the encryption key.
+        // Transforming the subject number of 17807724 (the door's public key) with a loop size of 8 (the card's loop size) produces the encryption key, 14897079.
+        // (Transforming the subject number of 5764801 (the card's public key) with a loop size of 11 (the door's loop size) produces the same encryption key: 14897079.)
+        let encryption_key_1 = transform(door_pub_key, card_loop_size);
+        let encryption_key_2 = transform(card_pub_key, door_loop_size);
+        if encryption_key_1 != encryption_key_2 {
+            return Err("the devices don't agree on the encryption key".into());
+        }
+
+        // Your puzzle answer was 11707042.
+        Ok(encryption_key_1)
+    }
+
+    fn part_2(_: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Err("There is no second puzzle on the last day.".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::{solve_example, Answer, Part};
+
+    const EXAMPLE: &str = "\
+5764801
+17807724
+";
+
+    #[test]
+    fn example() {
+        let answers = solve_example::<Puzzle>(EXAMPLE, Part::Both);
+        assert_eq!(answers.part_1, Some(Answer::Number(14897079)));
+    }
+
+    #[test]
+    fn bad_keys() {
+        let error = |s: &str| Puzzle::parse(s).unwrap_err().to_string();
+        assert_eq!(
+            error("5764801\n0\n"),
+            "line 2, column 1: the public key 0 is not from 1 to 20201226"
+        );
+        assert_eq!(
+            error("20201227\n5764801\n"),
+            "line 1, column 1: the public key 20201227 is not from 1 to 20201226"
+        );
+        assert_eq!(error("5764801\n"), "expected 2 public keys, found 1");
+    }
+}
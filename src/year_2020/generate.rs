@@ -0,0 +1,426 @@
+// Generators of valid puzzle inputs for stress and scale testing. The size scales
+// an input, 1 is about the size of an official one. The answers are worked out
+// along with the construction where it allows, independently of the solvers.
+use crate::puzzle::{Answer, Answers};
+use crate::rng::Rng;
+use std::collections::{HashMap, HashSet};
+
+// The days with a generator.
+pub const DAYS: [u8; 4] = [7, 10, 19, 21];
+
+pub const DEFAULT_SIZE: usize = 1;
+pub const DEFAULT_SEED: u64 = 2020;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: Answers,
+}
+
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<Generated> {
+    let mut rng = Rng::new(seed);
+    let size = size.max(1);
+    match day {
+        7 => Some(bags(&mut rng, size)),
+        10 => Some(adapters(&mut rng, size)),
+        19 => Some(messages(&mut rng, size)),
+        21 => Some(foods(&mut rng, size)),
+        _ => None,
+    }
+}
+
+// A pronounceable word like "kovami".
+fn word(rng: &mut Rng, syllables: usize) -> String {
+    const CONSONANTS: &[char] = &[
+        'b', 'd', 'f', 'g', 'k', 'l', 'm', 'n', 'p', 'r', 't', 'v', 'z',
+    ];
+    const VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u'];
+    let mut buf = String::new();
+    for _ in 0..syllables {
+        buf.push(*rng.choose(CONSONANTS));
+        buf.push(*rng.choose(VOWELS));
+    }
+    buf
+}
+
+// Different words, each made by the given function.
+fn unique_words<F>(rng: &mut Rng, count: usize, mut make: F) -> Vec<String>
+where
+    F: FnMut(&mut Rng) -> String,
+{
+    let mut seen = HashSet::new();
+    let mut words = Vec::with_capacity(count);
+    while words.len() < count {
+        let word = make(rng);
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    words
+}
+
+// Day 7: the rules form levels, a bag contains bags of the next level only.
+// That keeps the number of the nested bags within bounds. Fewer bags hold ours
+// directly the larger the input, so the bags around it don't grow with the size.
+fn bags(rng: &mut Rng, size: usize) -> Generated {
+    const OUR_COLOR: &str = "shiny gold";
+    const LEVELS: usize = 8;
+    const COLORS: &str = "aqua beige black blue bronze brown coral crimson cyan fuchsia \
+        gold gray green indigo lavender lime magenta maroon olive orange plum \
+        purple red salmon silver tan teal tomato turquoise violet white yellow";
+
+    let colors: Vec<&str> = COLORS.split_whitespace().collect();
+    let count = 594 * size;
+    // The words are made of consonant-vowel syllables, so "shiny" is never one of them.
+    // A word with "bag" inside is left out, the rules themselves are split on it.
+    let mut names = unique_words(rng, count - 1, |rng| loop {
+        let syllables = 2 + rng.below(2);
+        let word = word(rng, syllables);
+        if !word.contains("bag") {
+            break format!("{} {}", word, rng.choose(&colors));
+        }
+    });
+    let ours = rng.below(count);
+    names.insert(ours, OUR_COLOR.to_string());
+
+    let mut levels: Vec<usize> = (0..count).map(|_| rng.below(LEVELS)).collect();
+    // Our bag has bags around it and inside it.
+    levels[ours] = 1 + rng.below(LEVELS / 2);
+    let mut by_level = vec![Vec::new(); LEVELS];
+    for (i, &level) in levels.iter().enumerate() {
+        by_level[level].push(i);
+    }
+
+    let mut contents: Vec<Vec<(usize, usize)>> = vec![Vec::new(); count];
+    for (i, &level) in levels.iter().enumerate() {
+        let next = match by_level.get(level + 1) {
+            Some(next) if !next.is_empty() => next,
+            _ => continue,
+        };
+        let mut kinds: Vec<usize> = (0..rng.below(4)).map(|_| *rng.choose(next)).collect();
+        if level + 1 == levels[ours] && rng.below(5 * size) == 0 {
+            kinds.push(ours);
+        }
+        kinds.sort_unstable();
+        kinds.dedup();
+        contents[i] = kinds.into_iter().map(|k| (k, 1 + rng.below(5))).collect();
+    }
+
+    let mut input = String::new();
+    for (i, bag) in contents.iter().enumerate() {
+        let items: Vec<String> = bag
+            .iter()
+            .map(|&(k, n)| format!("{} {} bag{}", n, names[k], if n == 1 { "" } else { "s" }))
+            .collect();
+        let content = if items.is_empty() {
+            String::from("no other bags")
+        } else {
+            items.join(", ")
+        };
+        input.push_str(&format!("{} bags contain {}.\n", names[i], content));
+    }
+
+    // The bags which can eventually contain our one.
+    let mut holders = HashSet::new();
+    let mut queue = vec![ours];
+    while let Some(inner) = queue.pop() {
+        for (i, bag) in contents.iter().enumerate() {
+            if bag.iter().any(|&(k, _)| k == inner) && holders.insert(i) {
+                queue.push(i);
+            }
+        }
+    }
+
+    fn inside(
+        contents: &[Vec<(usize, usize)>],
+        bag: usize,
+        memo: &mut HashMap<usize, usize>,
+    ) -> usize {
+        if let Some(&n) = memo.get(&bag) {
+            return n;
+        }
+        let n = contents[bag]
+            .iter()
+            .map(|&(k, n)| n * (1 + inside(contents, k, memo)))
+            .sum();
+        memo.insert(bag, n);
+        n
+    }
+    let total = inside(&contents, ours, &mut HashMap::new());
+
+    Generated {
+        input,
+        answers: Answers {
+            part_1: Some(holders.len().into()),
+            part_2: Some(total.into()),
+        },
+    }
+}
+
+// Day 10: runs of adapters one jolt apart, separated by gaps of three jolts.
+fn adapters(rng: &mut Rng, size: usize) -> Generated {
+    // The arrangements of a run of 0 to 3 adapters one jolt apart.
+    const WAYS: [usize; 4] = [1, 1, 2, 4];
+
+    let count = 100 * size;
+    let mut chain = vec![0];
+    // The runs multiply the arrangements, a run that would overflow them is cut
+    // to a single adapter.
+    let mut arrangements: usize = 1;
+    while chain.len() <= count {
+        let last = chain[chain.len() - 1];
+        chain.push(last + 3);
+        let mut run = rng.below(4).min(count + 1 - chain.len());
+        match arrangements.checked_mul(WAYS[run]) {
+            Some(n) => arrangements = n,
+            None => run = 1,
+        }
+        for _ in 0..run {
+            let last = chain[chain.len() - 1];
+            chain.push(last + 1);
+        }
+    }
+    // Your device is 3 jolts higher than the highest adapter.
+    chain.push(chain[count] + 3);
+
+    let diffs: Vec<usize> = chain.windows(2).map(|w| w[1] - w[0]).collect();
+    let ones = diffs.iter().filter(|&&d| d == 1).count();
+    let threes = diffs.iter().filter(|&&d| d == 3).count();
+
+    let mut adapters = chain[1..=count].to_vec();
+    rng.shuffle(&mut adapters);
+    let input: String = adapters.iter().map(|a| format!("{}\n", a)).collect();
+
+    Generated {
+        input,
+        answers: Answers {
+            part_1: Some((ones * threes).into()),
+            part_2: Some(arrangements.into()),
+        },
+    }
+}
+
+// Day 19: rule 0 is "8 11" like in the puzzle, and rules 42 and 31 match chunks
+// of the same length starting with 'a' and 'b' respectively. A message is made
+// of m chunks of rule 42 and then n chunks of rule 31. Rule 0 matches m = 2, n = 1,
+// and the looping rules of the second part match m > n >= 1.
+fn messages(rng: &mut Rng, size: usize) -> Generated {
+    let mut chunk_len = 8;
+    while 1 << (chunk_len - 7) <= size {
+        chunk_len += 1;
+    }
+
+    let mut rules = vec![
+        String::from("0: 8 11"),
+        String::from("8: 42"),
+        String::from("11: 42 31"),
+    ];
+    let (a, b, any) = (100, 101, 102);
+    rules.push(format!("{}: \"a\"", a));
+    rules.push(format!("{}: \"b\"", b));
+    rules.push(format!("{}: {} | {}", any, a, b));
+
+    // Each position of a chunk is either a fixed letter or any letter.
+    let mut patterns = Vec::new();
+    for (id, first) in [(42, 'a'), (31, 'b')] {
+        let mut pattern = vec![Some(first)];
+        for _ in 1..chunk_len {
+            pattern.push(match rng.below(4) {
+                0 => Some('a'),
+                1 => Some('b'),
+                _ => None,
+            });
+        }
+        let ids: Vec<String> = pattern
+            .iter()
+            .map(|p| match p {
+                Some('a') => a.to_string(),
+                Some(_) => b.to_string(),
+                None => any.to_string(),
+            })
+            .collect();
+        rules.push(format!("{}: {}", id, ids.join(" ")));
+        patterns.push(pattern);
+    }
+    rng.shuffle(&mut rules);
+
+    let chunk = |rng: &mut Rng, pattern: &[Option<char>]| -> String {
+        pattern
+            .iter()
+            .map(|p| p.unwrap_or_else(|| *rng.choose(&['a', 'b'])))
+            .collect()
+    };
+
+    let (mut valid_1, mut valid_2) = (0, 0);
+    let mut lines = Vec::new();
+    for _ in 0..470 * size {
+        let (m, n) = match rng.below(4) {
+            0 => (2, 1),
+            1 => {
+                let n = 1 + rng.below(3);
+                (n + 1 + rng.below(3), n)
+            }
+            _ => loop {
+                let (m, n) = (rng.below(5), rng.below(4));
+                if m + n > 0 {
+                    break (m, n);
+                }
+            },
+        };
+        let mut message = String::new();
+        for _ in 0..m {
+            message.push_str(&chunk(rng, &patterns[0]));
+        }
+        for _ in 0..n {
+            message.push_str(&chunk(rng, &patterns[1]));
+        }
+
+        // A message of a broken length matches nothing.
+        if rng.chance(10) {
+            message.push(*rng.choose(&['a', 'b']));
+        } else {
+            if (m, n) == (2, 1) {
+                valid_1 += 1;
+            }
+            if m > n && n >= 1 {
+                valid_2 += 1;
+            }
+        }
+        lines.push(message);
+    }
+
+    let input = format!("{}\n\n{}\n", rules.join("\n"), lines.join("\n"));
+    Generated {
+        input,
+        answers: Answers {
+            part_1: Some(Answer::from(valid_1 as usize)),
+            part_2: Some(Answer::from(valid_2 as usize)),
+        },
+    }
+}
+
+// Day 21: every allergen is in exactly one ingredient. After the random foods,
+// one more food is added for each allergen, so that the candidates of an allergen
+// are its own ingredient and maybe ones of the allergens resolved before it.
+// Only the foods grow with the size: the solver checks every ingredient against
+// every allergen and food, so the allergens and ingredients are kept as many as
+// in the puzzle.
+fn foods(rng: &mut Rng, size: usize) -> Generated {
+    let allergens = unique_words(rng, 8, |rng| {
+        let syllables = 2 + rng.below(2);
+        word(rng, syllables)
+    });
+    let ingredients = unique_words(rng, 200 + allergens.len(), |rng| {
+        let len = 4 + rng.below(5);
+        (0..len)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect()
+    });
+    let (carriers, inert) = ingredients.split_at(allergens.len());
+
+    let mut foods: Vec<(Vec<usize>, Vec<usize>)> = Vec::new();
+    for _ in 0..40 * size {
+        let mut listed: Vec<usize> = (0..1 + rng.below(3))
+            .map(|_| rng.below(allergens.len()))
+            .collect();
+        listed.sort_unstable();
+        listed.dedup();
+        // Allergens aren't always marked.
+        let mut contents: Vec<usize> = listed.clone();
+        contents.extend((0..rng.below(4)).map(|_| rng.below(allergens.len())));
+        contents.extend((0..5 + rng.below(10)).map(|_| carriers.len() + rng.below(inert.len())));
+        foods.push((contents, listed));
+    }
+
+    let candidates =
+        |foods: &[(Vec<usize>, Vec<usize>)], allergen: usize| -> Option<HashSet<usize>> {
+            foods
+                .iter()
+                .filter(|(_, listed)| listed.contains(&allergen))
+                .map(|(contents, _)| contents.iter().copied().collect::<HashSet<usize>>())
+                .reduce(|a, b| a.intersection(&b).copied().collect())
+        };
+
+    let mut order: Vec<usize> = (0..allergens.len()).collect();
+    rng.shuffle(&mut order);
+    for (k, &allergen) in order.iter().enumerate() {
+        let known = candidates(&foods, allergen).unwrap_or_default();
+        let mut contents = vec![allergen];
+        if k > 0 && rng.chance(30) {
+            contents.push(order[rng.below(k)]);
+        }
+        while contents.len() < 6 {
+            let ingredient = carriers.len() + rng.below(inert.len());
+            if !known.contains(&ingredient) {
+                contents.push(ingredient);
+            }
+        }
+        let at = rng.below(foods.len() + 1);
+        foods.insert(at, (contents, vec![allergen]));
+    }
+
+    let mut input = String::new();
+    let mut inert_count = 0;
+    for (contents, listed) in &mut foods {
+        contents.sort_unstable();
+        contents.dedup();
+        rng.shuffle(contents);
+        inert_count += contents.iter().filter(|&&i| i >= carriers.len()).count();
+
+        let names: Vec<&str> = contents.iter().map(|&i| ingredients[i].as_str()).collect();
+        let listed: Vec<&str> = listed.iter().map(|&a| allergens[a].as_str()).collect();
+        input.push_str(&format!(
+            "{} (contains {})\n",
+            names.join(" "),
+            listed.join(", ")
+        ));
+    }
+
+    // The dangerous ingredients are sorted alphabetically by their allergen.
+    let mut by_name: Vec<usize> = (0..allergens.len()).collect();
+    by_name.sort_by_key(|&a| &allergens[a]);
+    let dangerous: Vec<&str> = by_name.iter().map(|&a| carriers[a].as_str()).collect();
+
+    Generated {
+        input,
+        answers: Answers {
+            part_1: Some(inert_count.into()),
+            part_2: Some(dangerous.join(",").into()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::Part;
+
+    #[test]
+    fn known_answers() {
+        for &day in DAYS.iter() {
+            for seed in 0..3 {
+                let generated = generate(day, 1, seed).unwrap();
+                let solve = crate::day(2020, day).unwrap().solve;
+                let solution = solve(&generated.input, Part::Both).unwrap();
+                assert_eq!(
+                    solution.answers, generated.answers,
+                    "day {}, seed {}",
+                    day, seed
+                );
+            }
+        }
+        assert_eq!(generate(7, 1, 5), generate(7, 1, 5));
+        assert!(generate(1, 1, 0).is_none());
+    }
+
+    #[test]
+    fn larger_sizes() {
+        // At size 4 the arrangements of day 10 would overflow without the cap.
+        for &day in DAYS.iter() {
+            let generated = generate(day, 4, 1).unwrap();
+            let solve = crate::day(2020, day).unwrap().solve;
+            let solution = solve(&generated.input, Part::Both).unwrap();
+            assert_eq!(solution.answers, generated.answers, "day {}", day);
+        }
+    }
+}
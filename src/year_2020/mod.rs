@@ -0,0 +1,186 @@
+// The Advent of Code 2020 puzzles.
+//
+// Each day module has a `Puzzle` implementing `puzzle::Solver` next to its
+// domain types, and `DAYS` lists them all for the front ends.
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod generate;
+
+use crate::puzzle::{self, Day};
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        title: "Report Repair",
+        input: "./data/2020/day-01.txt",
+        solve: puzzle::solve::<day_01::Puzzle>,
+    },
+    Day {
+        day: 2,
+        title: "Password Philosophy",
+        input: "./data/2020/day-02.txt",
+        solve: puzzle::solve::<day_02::Puzzle>,
+    },
+    Day {
+        day: 3,
+        title: "Toboggan Trajectory",
+        input: "./data/2020/day-03.txt",
+        solve: puzzle::solve::<day_03::Puzzle>,
+    },
+    Day {
+        day: 4,
+        title: "Passport Processing",
+        input: "./data/2020/day-04.txt",
+        solve: puzzle::solve::<day_04::Puzzle>,
+    },
+    Day {
+        day: 5,
+        title: "Binary Boarding",
+        input: "./data/2020/day-05.txt",
+        solve: puzzle::solve::<day_05::Puzzle>,
+    },
+    Day {
+        day: 6,
+        title: "Custom Customs",
+        input: "./data/2020/day-06.txt",
+        solve: puzzle::solve::<day_06::Puzzle>,
+    },
+    Day {
+        day: 7,
+        title: "Handy Haversacks",
+        input: "./data/2020/day-07.txt",
+        solve: puzzle::solve::<day_07::Puzzle>,
+    },
+    Day {
+        day: 8,
+        title: "Handheld Halting",
+        input: "./data/2020/day-08.txt",
+        solve: puzzle::solve::<day_08::Puzzle>,
+    },
+    Day {
+        day: 9,
+        title: "Encoding Error",
+        input: "./data/2020/day-09.txt",
+        solve: puzzle::solve::<day_09::Puzzle>,
+    },
+    Day {
+        day: 10,
+        title: "Adapter Array",
+        input: "./data/2020/day-10.txt",
+        solve: puzzle::solve::<day_10::Puzzle>,
+    },
+    Day {
+        day: 11,
+        title: "Seating System",
+        input: "./data/2020/day-11.txt",
+        solve: puzzle::solve::<day_11::Puzzle>,
+    },
+    Day {
+        day: 12,
+        title: "Rain Risk",
+        input: "./data/2020/day-12.txt",
+        solve: puzzle::solve::<day_12::Puzzle>,
+    },
+    Day {
+        day: 13,
+        title: "Shuttle Search",
+        input: "./data/2020/day-13.txt",
+        solve: puzzle::solve::<day_13::Puzzle>,
+    },
+    Day {
+        day: 14,
+        title: "Docking Data",
+        input: "./data/2020/day-14.txt",
+        solve: puzzle::solve::<day_14::Puzzle>,
+    },
+    Day {
+        day: 15,
+        title: "Rambunctious Recitation",
+        input: "./data/2020/day-15.txt",
+        solve: puzzle::solve::<day_15::Puzzle>,
+    },
+    Day {
+        day: 16,
+        title: "Ticket Translation",
+        input: "./data/2020/day-16.txt",
+        solve: puzzle::solve::<day_16::Puzzle>,
+    },
+    Day {
+        day: 17,
+        title: "Conway Cubes",
+        input: "./data/2020/day-17.txt",
+        solve: puzzle::solve::<day_17::Puzzle>,
+    },
+    Day {
+        day: 18,
+        title: "Operation Order",
+        input: "./data/2020/day-18.txt",
+        solve: puzzle::solve::<day_18::Puzzle>,
+    },
+    Day {
+        day: 19,
+        title: "Monster Messages",
+        input: "./data/2020/day-19.txt",
+        solve: puzzle::solve::<day_19::Puzzle>,
+    },
+    Day {
+        day: 20,
+        title: "Jurassic Jigsaw",
+        input: "./data/2020/day-20.txt",
+        solve: puzzle::solve::<day_20::Puzzle>,
+    },
+    Day {
+        day: 21,
+        title: "Allergen Assessment",
+        input: "./data/2020/day-21.txt",
+        solve: puzzle::solve::<day_21::Puzzle>,
+    },
+    Day {
+        day: 22,
+        title: "Crab Combat",
+        input: "./data/2020/day-22.txt",
+        solve: puzzle::solve::<day_22::Puzzle>,
+    },
+    Day {
+        day: 23,
+        title: "Crab Cups",
+        input: "./data/2020/day-23.txt",
+        solve: puzzle::solve::<day_23::Puzzle>,
+    },
+    Day {
+        day: 24,
+        title: "Lobby Layout",
+        input: "./data/2020/day-24.txt",
+        solve: puzzle::solve::<day_24::Puzzle>,
+    },
+    Day {
+        day: 25,
+        title: "Combo Breaker",
+        input: "./data/2020/day-25.txt",
+        solve: puzzle::solve::<day_25::Puzzle>,
+    },
+];
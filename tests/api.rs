@@ -0,0 +1,44 @@
+use aoc::puzzle::{Answer, Part};
+use aoc::year_2020::day_17::PowerGrid;
+use aoc::year_2020::day_18::{evaluate_expression, Precedence};
+use aoc::year_2020::day_23::CupCircle;
+use aoc::year_2020::day_24::HexPos;
+
+#[test]
+fn domain_types() {
+    let mut grid: PowerGrid = ".#.\n..#\n###\n".parse().unwrap();
+    for _ in 0..6 {
+        grid = grid.next_3d();
+    }
+    assert_eq!(grid.active(), 112);
+
+    let expr = "2 * 3 + (4 * 5)";
+    assert_eq!(evaluate_expression(expr, Precedence::Equal).unwrap(), 26);
+    assert_eq!(
+        evaluate_expression(expr, Precedence::AdditionFirst).unwrap(),
+        46
+    );
+    assert!(evaluate_expression("2 * (3 + 4", Precedence::Equal).is_err());
+
+    let mut circle = CupCircle::new(389125467, 9);
+    for _ in 0..10 {
+        circle.next_move();
+    }
+    assert_eq!(circle.after_one_order(), 92658374);
+
+    let pos: HexPos = "nwwswee".parse().unwrap();
+    assert_eq!(pos, HexPos::default());
+    assert_eq!("esew".parse::<HexPos>().unwrap().coordinates(), (1, -1));
+}
+
+#[test]
+fn registry() {
+    let day = aoc::day(2020, 1).unwrap();
+    assert_eq!(day.title, "Report Repair");
+
+    let solution = (day.solve)("1721\n979\n366\n299\n675\n1456\n", Part::Both).unwrap();
+    assert_eq!(solution.answers.part_1, Some(Answer::Number(514579)));
+    assert_eq!(solution.answers.part_2, Some(Answer::Number(241861950)));
+    assert!(aoc::day(2020, 26).is_none());
+    assert!(aoc::day(2019, 1).is_none());
+}